@@ -1,5 +1,6 @@
 #![allow(non_snake_case, non_upper_case_globals)]
 
+#[macro_use]
 extern crate metric;
 
 use self::metric::time::Second;
@@ -8,7 +9,7 @@ use self::metric::length::metric::Meter;
 use self::metric::mass::metric::Kilogram;
 use self::metric::composite::*;
 
-const G: Mul<Newton, Mul<Meter, Div<Meter, Mul<Kilogram, Kilogram>>>> = Mul(Mul(Kilogram(6.674e-11), PhantomData), PhantomData);
+const G: unit!(N * m^2 / kg^2) = qty!(6.674e-11 N * m^2 / kg^2);
 
 #[derive(Copy, Clone)]
 struct MetricNBody {
@@ -18,18 +19,14 @@ struct MetricNBody {
     mass: Kilogram,
 }
 
-use std::marker::PhantomData;
-
 ///Meter per Second^2
 type MPSS = Div<Meter, Mul<Second, Second>>;
-const MPSS_ZERO: MPSS = Div(Meter(0.0), PhantomData);
 
 #[derive(Copy, Clone, Debug)]
 struct Accel2D(MPSS, MPSS);
 
 ///Meter per Second
 type MPS = Div<Meter, Second>;
-const MPS_ZERO: MPS = Div(Meter(0.0), PhantomData);
 
 #[derive(Copy, Clone, Debug)]
 struct Velocity2D(MPS, MPS);
@@ -49,26 +46,26 @@ impl Position2D {
 
 const MetricNBodies: [MetricNBody; 4] = [MetricNBody {
                                              position: Position2D(Meter(1500.0), Meter(2500.0)),
-                                             accel: Accel2D(MPSS_ZERO, MPSS_ZERO),
-                                             velocity: Velocity2D(MPS_ZERO, MPS_ZERO),
+                                             accel: Accel2D(MPSS::ZERO, MPSS::ZERO),
+                                             velocity: Velocity2D(MPS::ZERO, MPS::ZERO),
                                              mass: Kilogram(2000.0),
                                          },
                                          MetricNBody {
                                              position: Position2D(Meter(3500.0), Meter(500.0)),
-                                             accel: Accel2D(MPSS_ZERO, MPSS_ZERO),
-                                             velocity: Velocity2D(MPS_ZERO, MPS_ZERO),
+                                             accel: Accel2D(MPSS::ZERO, MPSS::ZERO),
+                                             velocity: Velocity2D(MPS::ZERO, MPS::ZERO),
                                              mass: Kilogram(2000.0),
                                          },
                                          MetricNBody {
                                              position: Position2D(Meter(200.0), Meter(4500.0)),
-                                             accel: Accel2D(MPSS_ZERO, MPSS_ZERO),
-                                             velocity: Velocity2D(MPS_ZERO, MPS_ZERO),
+                                             accel: Accel2D(MPSS::ZERO, MPSS::ZERO),
+                                             velocity: Velocity2D(MPS::ZERO, MPS::ZERO),
                                              mass: Kilogram(2000.0),
                                          },
                                          MetricNBody {
                                              position: Position2D(Meter(-1500.0), Meter(750.0)),
-                                             accel: Accel2D(MPSS_ZERO, MPSS_ZERO),
-                                             velocity: Velocity2D(MPS_ZERO, MPS_ZERO),
+                                             accel: Accel2D(MPSS::ZERO, MPSS::ZERO),
+                                             velocity: Velocity2D(MPS::ZERO, MPS::ZERO),
                                              mass: Kilogram(2000.0),
                                          }];
 
@@ -78,7 +75,7 @@ pub fn metric_nbody() {
     for _ in 0..10000 {
         //calculate accelerations
         for a in 0..bodies.len() {
-            bodies[a].accel = Accel2D(MPSS_ZERO, MPSS_ZERO);
+            bodies[a].accel = Accel2D(MPSS::ZERO, MPSS::ZERO);
             for b in 0..bodies.len() {
                 if a == b {
                     continue;
@@ -91,8 +88,8 @@ pub fn metric_nbody() {
                 let force: Newton = G.divide_right((dist * dist) / (Ma * Mb));
                 let Fx: Newton = force * (Dx / Dy);
                 let Fy: Newton = force * (Dy / Dx);
-                let Ax: MPSS = Fx.divide_left(Ma);
-                let Ay: MPSS = Fy.divide_left(Ma);
+                let Ax: MPSS = Mul::<Kilogram, MPSS>::from(Fx).divide_left(Ma);
+                let Ay: MPSS = Mul::<Kilogram, MPSS>::from(Fy).divide_left(Ma);
                 bodies[a].accel = Accel2D(bodies[a].accel.0 + Ax, bodies[a].accel.1 + Ay);
             }
         }
@@ -0,0 +1,313 @@
+//! Provides `#[derive(Quantities)]`, re-exported by `metric` under the `derive` feature.
+//!
+//! See the documentation of `metric::Quantities` for what the derive generates.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+
+struct Field {
+    //the field name, or its index for tuple structs
+    member: String,
+    skip: bool,
+}
+
+struct Input {
+    name: String,
+    named: bool,
+    fields: Vec<Field>,
+    into: Vec<String>,
+    //the path of the `metric` crate, `::metric` unless renamed with `#[quantities(crate = ...)]`
+    krate: String,
+}
+
+#[proc_macro_derive(Quantities, attributes(quantities))]
+pub fn derive_quantities(input: TokenStream) -> TokenStream {
+    let code = match parse(input) {
+        Ok(input) => expand(&input),
+        Err(msg) => format!("compile_error!({:?});", msg),
+    };
+    code.parse().unwrap()
+}
+
+fn is_punct(tt: Option<&TokenTree>, ch: char) -> bool {
+    match tt {
+        Some(TokenTree::Punct(p)) => p.as_char() == ch,
+        _ => false,
+    }
+}
+
+fn is_ident(tt: Option<&TokenTree>, name: &str) -> bool {
+    match tt {
+        Some(TokenTree::Ident(id)) => id.to_string() == name,
+        _ => false,
+    }
+}
+
+//splits a token stream at the commas outside of any angle brackets
+fn split_commas(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0;
+    let mut after_dash = false;
+    for tt in stream {
+        if let TokenTree::Punct(ref p) = tt {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !after_dash => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(Vec::new());
+                    after_dash = false;
+                    continue;
+                }
+                _ => {}
+            }
+            after_dash = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            after_dash = false;
+        }
+        parts.last_mut().unwrap().push(tt);
+    }
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+//returns the arguments of a `#[quantities(...)]` attribute, or `None` for any other attribute
+fn quantities_args(attr: &Group) -> Option<TokenStream> {
+    let tokens: Vec<TokenTree> = attr.stream().into_iter().collect();
+    if !is_ident(tokens.first(), "quantities") {
+        return None;
+    }
+    match tokens.get(1) {
+        Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => Some(args.stream()),
+        _ => Some(TokenStream::new()),
+    }
+}
+
+//reads the leading attributes of an item or field, returning the index of the first token after them
+fn parse_attrs<F>(tokens: &[TokenTree], mut handle: F) -> Result<usize, String>
+    where F: FnMut(TokenStream) -> Result<(), String>
+{
+    let mut i = 0;
+    while is_punct(tokens.get(i), '#') {
+        if let Some(TokenTree::Group(attr)) = tokens.get(i + 1) {
+            if let Some(args) = quantities_args(attr) {
+                handle(args)?;
+            }
+        }
+        i += 2;
+    }
+    Ok(i)
+}
+
+fn skip_visibility(tokens: &[TokenTree], mut i: usize) -> usize {
+    if is_ident(tokens.get(i), "pub") {
+        i += 1;
+        if let Some(TokenTree::Group(group)) = tokens.get(i) {
+            if group.delimiter() == Delimiter::Parenthesis {
+                i += 1;
+            }
+        }
+    }
+    i
+}
+
+fn parse(input: TokenStream) -> Result<Input, String> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut into = Vec::new();
+    let mut krate = "::metric".to_string();
+    let mut i = parse_attrs(&tokens, |args| {
+        let args: Vec<TokenTree> = args.into_iter().collect();
+        match (args.first(), args.get(1)) {
+            (Some(TokenTree::Ident(id)), Some(TokenTree::Group(targets))) if id.to_string() == "into" => {
+                for target in split_commas(targets.stream()) {
+                    into.push(target.into_iter().collect::<TokenStream>().to_string());
+                }
+                Ok(())
+            }
+            (Some(TokenTree::Ident(id)), Some(eq)) if id.to_string() == "crate" && is_punct(Some(eq), '=') &&
+                                                      args.len() > 2 => {
+                krate = args[2..].iter().cloned().collect::<TokenStream>().to_string();
+                Ok(())
+            }
+            _ => Err("expected `#[quantities(into(Type, ...))]` or `#[quantities(crate = path)]`".to_string()),
+        }
+    })?;
+    i = skip_visibility(&tokens, i);
+
+    if !is_ident(tokens.get(i), "struct") {
+        return Err("#[derive(Quantities)] is only supported on structs".to_string());
+    }
+    let name = match tokens.get(i + 1) {
+        Some(TokenTree::Ident(id)) => id.to_string(),
+        _ => return Err("expected a struct name".to_string()),
+    };
+    if is_punct(tokens.get(i + 2), '<') {
+        return Err("#[derive(Quantities)] does not support generic structs".to_string());
+    }
+    let (named, body) = match tokens.get(i + 2) {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => (true, body.stream()),
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Parenthesis => (false, body.stream()),
+        _ => return Err("#[derive(Quantities)] needs a struct with fields".to_string()),
+    };
+
+    let mut fields = Vec::new();
+    for (index, field) in split_commas(body).into_iter().enumerate() {
+        let mut skip = false;
+        let mut i = parse_attrs(&field, |args| {
+            if args.to_string() == "skip" {
+                skip = true;
+                Ok(())
+            } else {
+                Err("expected `#[quantities(skip)]`".to_string())
+            }
+        })?;
+        i = skip_visibility(&field, i);
+        let member = if named {
+            match field.get(i) {
+                Some(TokenTree::Ident(id)) => id.to_string(),
+                _ => return Err("expected a field name".to_string()),
+            }
+        } else {
+            index.to_string()
+        };
+        fields.push(Field { member, skip });
+    }
+
+    Ok(Input { name, named, fields, into, krate })
+}
+
+//builds a value of `ty` with the shape of the input struct, one expression per field
+fn construct<F>(input: &Input, ty: &str, mut value: F) -> String
+    where F: FnMut(&Field) -> String
+{
+    let values: Vec<String> = input.fields
+        .iter()
+        .map(|field| if input.named {
+            format!("{}: {}", field.member, value(field))
+        } else {
+            value(field)
+        })
+        .collect();
+    if input.named {
+        format!("{} {{ {} }}", ty, values.join(", "))
+    } else {
+        format!("{}({})", ty, values.join(", "))
+    }
+}
+
+fn expand(input: &Input) -> String {
+    let name = &input.name;
+    let krate = &input.krate;
+    let mut code = String::new();
+
+    for &(op, method, symbol) in &[("Add", "add", "+"), ("Sub", "sub", "-")] {
+        let body = construct(input, name, |field| if field.skip {
+            format!("self.{}", field.member)
+        } else {
+            format!("self.{0} {1} other.{0}", field.member, symbol)
+        });
+        code += &format!("
+            impl {krate}::__core::ops::{op} for {name} {{
+                type Output = {name};
+
+                #[allow(unused_variables)]
+                fn {method}(self, other: {name}) -> {name} {{
+                    {body}
+                }}
+            }}",
+            op = op, method = method, name = name, body = body, krate = krate);
+
+        let body: String = input.fields
+            .iter()
+            .filter(|field| !field.skip)
+            .map(|field| format!("self.{0} {1}= other.{0};", field.member, symbol))
+            .collect();
+        code += &format!("
+            impl {krate}::__core::ops::{op}Assign for {name} {{
+                #[allow(unused_variables)]
+                fn {method}_assign(&mut self, other: {name}) {{
+                    {body}
+                }}
+            }}",
+            op = op, method = method, name = name, body = body, krate = krate);
+    }
+
+    for &(op, method, symbol) in &[("Mul", "mul", "*"), ("Div", "div", "/")] {
+        let body = construct(input, name, |field| if field.skip {
+            format!("self.{}", field.member)
+        } else {
+            format!("self.{} {} factor", field.member, symbol)
+        });
+        code += &format!("
+            impl {krate}::__core::ops::{op}<f64> for {name} {{
+                type Output = {name};
+
+                #[allow(unused_variables)]
+                fn {method}(self, factor: f64) -> {name} {{
+                    {body}
+                }}
+            }}",
+            op = op, method = method, name = name, body = body, krate = krate);
+
+        let body: String = input.fields
+            .iter()
+            .filter(|field| !field.skip)
+            .map(|field| format!("self.{} {}= factor;", field.member, symbol))
+            .collect();
+        code += &format!("
+            impl {krate}::__core::ops::{op}Assign<f64> for {name} {{
+                #[allow(unused_variables)]
+                fn {method}_assign(&mut self, factor: f64) {{
+                    {body}
+                }}
+            }}",
+            op = op, method = method, name = name, body = body, krate = krate);
+    }
+
+    code += &format!("
+        impl {krate}::__core::ops::Mul<{name}> for f64 {{
+            type Output = {name};
+
+            fn mul(self, value: {name}) -> {name} {{
+                value * self
+            }}
+        }}",
+        name = name, krate = krate);
+
+    let (open, close) = if input.named { (" { ", " }") } else { ("(", ")") };
+    let mut body = format!("f.write_str({:?})?;", format!("{}{}", name, open));
+    for (i, field) in input.fields.iter().filter(|field| !field.skip).enumerate() {
+        if i > 0 {
+            body += "f.write_str(\", \")?;";
+        }
+        if input.named {
+            body += &format!("f.write_str({:?})?;", format!("{}: ", field.member));
+        }
+        body += &format!("{}::__core::fmt::Debug::fmt(&self.{}, f)?;", krate, field.member);
+    }
+    body += &format!("f.write_str({:?})", close);
+    code += &format!("
+        impl {krate}::__core::fmt::Display for {name} {{
+            fn fmt(&self, f: &mut {krate}::__core::fmt::Formatter) -> {krate}::__core::fmt::Result {{
+                {body}
+            }}
+        }}",
+        name = name, body = body, krate = krate);
+
+    for target in &input.into {
+        let body = construct(input, target, |field| if field.skip {
+            format!("value.{}", field.member)
+        } else {
+            format!("{}::composite::Quantity::convert(value.{})", krate, field.member)
+        });
+        code += &format!("
+            impl {krate}::__core::convert::From<{name}> for {target} {{
+                fn from(value: {name}) -> {target} {{
+                    {body}
+                }}
+            }}",
+            name = name, target = target, body = body, krate = krate);
+    }
+
+    code
+}
@@ -1,19 +1,20 @@
 extern crate metric;
 use std::fmt::Debug;
+use metric::dimension::Temperature;
 use metric::temperature::*;
 
 /// will determine whether a given temperature is in danger of
 /// reaching the freezing point of water
-pub fn in_danger_of_freezing<T>(temp: &T) -> bool
-    where T: PartialOrd<Celsius>
+pub fn in_danger_of_freezing<T>(temp: T) -> bool
+    where T: Temperature
 {
-    *temp < Celsius(5.0)
+    temp.to_si() < Celsius(5.0)
 }
 
 pub fn print_danger<T>(temp: T)
-    where T: PartialOrd<Celsius> + Debug
+    where T: Temperature + Debug
 {
-    let could_freeze = in_danger_of_freezing(&temp);
+    let could_freeze = in_danger_of_freezing(temp);
     let danger = if !could_freeze { " not" } else { "" };
     println!("{:?} is{} in danger of freezing!", temp, danger);
 }
@@ -0,0 +1,220 @@
+//! Units of plane angle, with trigonometry.
+//!
+//! Every angle unit, prefixed radians such as `Milliradian` included, has `sin`, `cos` and `tan`, and can be built
+//! from the inverse functions, including `atan2` of two quantities of the same dimension, such as the sides of a
+//! triangle in any units of length.
+//!
+//! ```
+//! use metric::angle::{Degree, Milliradian, Radian};
+//! use metric::length::imperial::Foot;
+//! use metric::length::metric::Meter;
+//!
+//! let heading = Degree::atan2(Meter(1.0), Foot(0.0));
+//! assert_eq!(heading, Degree(90.0));
+//! assert!((Degree(30.0).sin() - 0.5).abs() < 1e-12);
+//! assert_eq!(Degree(-90.0).wrap(), Degree(270.0));
+//! assert_eq!(Radian::from(Degree(540.0).wrap_signed()), Radian(core::f64::consts::PI));
+//! assert!((Milliradian::new(500.0).sin() - 0.5f64.sin()).abs() < 1e-12);
+//! assert_eq!(Milliradian::asin(1.0), Milliradian::TURN / 4.0);
+//! assert_eq!(Milliradian::new(-1000.0).wrap(), Milliradian::TURN - Milliradian::new(1000.0));
+//! ```
+
+use core::f64::consts::PI;
+
+use prefix::{Milli, Prefix, Prefixed};
+
+//impl_angle gives an angle unit its trigonometry and wrapping, with `TURN` computed from its scale unless given
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_angle {
+    ($unit:ident) => {
+        $crate::impl_angle!(@generic [] $unit);
+    };
+    ($unit:ident, $turn:expr) => {
+        $crate::impl_angle!(@generic [] $unit, $turn);
+    };
+    (@generic [$($gen:tt)*] $unit:ty) => {
+        $crate::impl_angle!(@generic [$($gen)*] $unit, <$crate::angle::Turn as $crate::composite::Quantity>::SCALE /
+                                                       <$unit as $crate::composite::Quantity>::SCALE);
+    };
+    (@generic [$($gen:tt)*] $unit:ty, $turn:expr) => {
+        impl<$($gen)*> $unit {
+            /// One full turn in this unit.
+            pub const TURN: $unit = $crate::composite::from_raw_value($turn);
+
+            /// Wraps `self` into the range `[0, TURN)`, e.g. `[0°, 360°)`.
+            #[inline]
+            pub fn wrap(self) -> $unit {
+                let turns = self.0 % Self::TURN.0;
+                let wrapped = if turns < 0.0 { turns + Self::TURN.0 } else { turns };
+                //a tiny negative angle rounds up to a full turn when wrapped
+                $crate::composite::from_raw_value(if wrapped >= Self::TURN.0 { 0.0 } else { wrapped })
+            }
+
+            /// Wraps `self` into the range `(-TURN / 2, TURN / 2]`, e.g. `(-180°, 180°]`.
+            #[inline]
+            pub fn wrap_signed(self) -> $unit {
+                let wrapped = self.wrap().0;
+                let signed = if wrapped > Self::TURN.0 / 2.0 { wrapped - Self::TURN.0 } else { wrapped };
+                $crate::composite::from_raw_value(signed)
+            }
+        }
+
+        $crate::__if_math! {
+            impl<$($gen)*> $unit {
+                /// Returns the sine of `self`.
+                #[inline(always)]
+                pub fn sin(self) -> f64 {
+                    $crate::math::sin($crate::composite::convert_const::<$unit, $crate::angle::Radian>(self).0)
+                }
+
+                /// Returns the cosine of `self`.
+                #[inline(always)]
+                pub fn cos(self) -> f64 {
+                    $crate::math::cos($crate::composite::convert_const::<$unit, $crate::angle::Radian>(self).0)
+                }
+
+                /// Returns the tangent of `self`.
+                #[inline(always)]
+                pub fn tan(self) -> f64 {
+                    $crate::math::tan($crate::composite::convert_const::<$unit, $crate::angle::Radian>(self).0)
+                }
+
+                /// Returns the sine and the cosine of `self`.
+                #[inline(always)]
+                pub fn sin_cos(self) -> (f64, f64) {
+                    (self.sin(), self.cos())
+                }
+
+                /// Returns the angle whose sine is `x`, in `[-TURN / 4, TURN / 4]`.
+                #[inline(always)]
+                pub fn asin(x: f64) -> $unit {
+                    $crate::composite::convert_const($crate::angle::Radian($crate::math::asin(x)))
+                }
+
+                /// Returns the angle whose cosine is `x`, in `[0, TURN / 2]`.
+                #[inline(always)]
+                pub fn acos(x: f64) -> $unit {
+                    $crate::composite::convert_const($crate::angle::Radian($crate::math::acos(x)))
+                }
+
+                /// Returns the angle whose tangent is `x`, in `[-TURN / 4, TURN / 4]`.
+                #[inline(always)]
+                pub fn atan(x: f64) -> $unit {
+                    $crate::composite::convert_const($crate::angle::Radian($crate::math::atan(x)))
+                }
+
+                /// Returns the angle of the point `(x, y)` from the positive x axis, in `(-TURN / 2, TURN / 2]`.
+                ///
+                /// `y` and `x` may be in different units of the same dimension.
+                #[inline(always)]
+                pub fn atan2<Y, X>(y: Y, x: X) -> $unit
+                    where Y: $crate::composite::Quantity,
+                          X: $crate::composite::Quantity<Base = Y::Base>
+                {
+                    let y = $crate::composite::Unit::inner(&$crate::composite::Quantity::to_base(y));
+                    let x = $crate::composite::Unit::inner(&$crate::composite::Quantity::to_base(x));
+                    $crate::composite::convert_const($crate::angle::Radian($crate::math::atan2(y, x)))
+                }
+            }
+        }
+    }
+}
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Radian(pub f64);
+pub type Radians = Radian;
+
+impl_full_unit!(Radian);
+impl_unit_debug!(Radian => "rad");
+impl_quantity!(Radian => Radian, 1.0);
+impl_composite_base!(Radian);
+impl_angle!(Radian, 2.0 * PI);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Degree(pub f64);
+pub type Degrees = Degree;
+
+impl_full_unit!(Degree);
+impl_unit_debug!(Degree => "°");
+impl_quantity!(Degree => Radian, PI / 180.0);
+impl_composite_base!(Degree);
+impl_angle!(Degree, 360.0);
+
+impl_from_quantity!(Degree, Radian);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Gradian(pub f64);
+pub type Gradians = Gradian;
+
+impl_full_unit!(Gradian);
+impl_unit_debug!(Gradian => "gon");
+impl_quantity!(Gradian => Radian, PI / 200.0);
+impl_composite_base!(Gradian);
+impl_angle!(Gradian, 400.0);
+
+impl_from_quantity!(Gradian, Radian);
+impl_from_quantity!(Gradian, Degree);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Turn(pub f64);
+pub type Turns = Turn;
+
+impl_full_unit!(Turn);
+impl_unit_debug!(Turn => "tr");
+impl_quantity!(Turn => Radian, 2.0 * PI);
+impl_composite_base!(Turn);
+impl_angle!(Turn, 1.0);
+
+impl_from_quantity!(Turn, Radian);
+impl_from_quantity!(Turn, Degree);
+impl_from_quantity!(Turn, Gradian);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Arcminute(pub f64);
+pub type Arcminutes = Arcminute;
+
+impl_full_unit!(Arcminute);
+impl_unit_debug!(Arcminute => "′");
+impl_quantity!(Arcminute => Radian, PI / 10_800.0);
+impl_composite_base!(Arcminute);
+impl_angle!(Arcminute, 21_600.0);
+
+impl_from_quantity!(Arcminute, Radian);
+impl_from_quantity!(Degree, Arcminute);
+impl_from_quantity!(Gradian, Arcminute);
+impl_from_quantity!(Turn, Arcminute);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Arcsecond(pub f64);
+pub type Arcseconds = Arcsecond;
+
+impl_full_unit!(Arcsecond);
+impl_unit_debug!(Arcsecond => "″");
+impl_quantity!(Arcsecond => Radian, PI / 648_000.0);
+impl_composite_base!(Arcsecond);
+impl_angle!(Arcsecond, 1_296_000.0);
+
+impl_from_quantity!(Arcsecond, Radian);
+impl_from_quantity!(Degree, Arcsecond);
+impl_from_quantity!(Gradian, Arcsecond);
+impl_from_quantity!(Turn, Arcsecond);
+impl_from_quantity!(Arcminute, Arcsecond);
+
+/// A thousandth of a radian, as a prefixed `Radian`.
+pub type Milliradian = Prefixed<Milli, Radian>;
+pub type Milliradians = Milliradian;
+
+impl_angle!(@generic [P: Prefix] Prefixed<P, Radian>);
@@ -0,0 +1,114 @@
+//! Approximate equality for units, along with the `assert_quantity_eq!` family of macros.
+//!
+//! Every unit and composite provides `abs_diff_eq`, `relative_eq` and `ulps_eq` methods that convert the other
+//! value into the unit of `self` before comparing. With the `approx` feature enabled they also implement the
+//! `AbsDiffEq`, `RelativeEq` and `UlpsEq` traits from the `approx` crate, with tolerances given in the unit of `self`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate metric;
+//!
+//! use metric::composite::Div;
+//! use metric::length::imperial::Foot;
+//! use metric::length::metric::{Centimeter, Meter, Millimeter};
+//! use metric::time::{Hour, Second};
+//!
+//! fn main() {
+//!     assert!(Meter(1.0) != Foot(3.280841666667));
+//!     assert!(Meter(1.0).abs_diff_eq(Foot(3.280841666667), Millimeter(0.01)));
+//!     assert!(!Meter(1.0).abs_diff_eq(Centimeter(99.0), Millimeter(5.0)));
+//!     assert!(Meter(1000.0).relative_eq(Meter(1000.5), 1e-3) && !Meter(1.0).relative_eq(Meter(1.5), 1e-3));
+//!     assert!(Meter(0.1 + 0.2).ulps_eq(Meter(0.3), 1) && !Meter(0.1 + 0.2).ulps_eq(Meter(0.3), 0));
+//!     assert!(Meter(0.3048).ulps_eq(Foot(1.0), 1));
+//!
+//!     let speed: Div<Meter, Second> = Meter(10.0) / Second(1.0);
+//!     assert!(speed.abs_diff_eq(Meter(36.001) / Hour(0.001), Millimeter(1.0) / Second(1.0)));
+//!     assert!(speed.relative_eq(Foot(32.8084) / Second(1.0), 1e-6));
+//!
+//!     assert_quantity_eq!(Meter(1.0), Foot(3.28084), Millimeter(0.01));
+//!     assert_quantity_relative_eq!(speed, Meter(10.00001) / Second(1.0), 1e-5);
+//!     assert_quantity_ulps_eq!(Meter(0.1 + 0.2), Meter(0.3), 1);
+//! }
+//! ```
+
+/// Returns `true` if `a` and `b` differ by no more than `epsilon`.
+#[inline(always)]
+pub fn abs_diff_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    a == b || (a - b).abs() <= epsilon.abs()
+}
+
+/// Returns `true` if `a` and `b` differ by no more than `max_relative` times the larger of their magnitudes.
+#[inline(always)]
+pub fn relative_eq(a: f64, b: f64, max_relative: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let largest = a.abs().max(b.abs());
+    (a - b).abs() <= largest * max_relative
+}
+
+/// Returns `true` if `a` and `b` are no more than `max_ulps` representable values apart.
+#[inline(always)]
+pub fn ulps_eq(a: f64, b: f64, max_ulps: u32) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_nan() || b.is_nan() || a.is_sign_positive() != b.is_sign_positive() {
+        return false;
+    }
+    let a = a.to_bits() as i64;
+    let b = b.to_bits() as i64;
+    (a - b).abs() <= i64::from(max_ulps)
+}
+
+/// Asserts that two quantities are equal to within an absolute tolerance.
+///
+/// The right hand side and the tolerance may be given in any unit that converts into the unit of the left hand side,
+/// e.g. `assert_quantity_eq!(Meter(1.0), Foot(3.28084), Millimeter(0.01))`.
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        match (&$left, &$right, &$epsilon) {
+            (left_val, right_val, epsilon_val) => {
+                if !left_val.abs_diff_eq(*right_val, *epsilon_val) {
+                    panic!("assertion failed: `left ≈ right`\n    left: `{:?}`\n   right: `{:?}`\n epsilon: `{:?}`",
+                           left_val, right_val, epsilon_val);
+                }
+            }
+        }
+    }
+}
+
+/// Asserts that two quantities are equal to within a relative tolerance of the larger magnitude.
+#[macro_export]
+macro_rules! assert_quantity_relative_eq {
+    ($left:expr, $right:expr, $max_relative:expr) => {
+        match (&$left, &$right, $max_relative) {
+            (left_val, right_val, max_relative) => {
+                if !left_val.relative_eq(*right_val, max_relative) {
+                    panic!("assertion failed: `left ≈ right`\n         left: `{:?}`\n        right: `{:?}`\n max_relative: `{:?}`",
+                           left_val, right_val, max_relative);
+                }
+            }
+        }
+    }
+}
+
+/// Asserts that two quantities are no more than the given number of ULPs apart, once converted to the unit of the
+/// left hand side.
+#[macro_export]
+macro_rules! assert_quantity_ulps_eq {
+    ($left:expr, $right:expr, $max_ulps:expr) => {
+        match (&$left, &$right, $max_ulps) {
+            (left_val, right_val, max_ulps) => {
+                if !left_val.ulps_eq(*right_val, max_ulps) {
+                    panic!("assertion failed: `left ≈ right`\n     left: `{:?}`\n    right: `{:?}`\n max_ulps: `{:?}`",
+                           left_val, right_val, max_ulps);
+                }
+            }
+        }
+    }
+}
@@ -1,24 +1,64 @@
-use length::astronomical::*;
-use length::imperial::*;
-use length::metric::*;
-
-use mass::imperial::*;
-use mass::metric::*;
-
-use mass::imperial::Ton as ITon;
-use mass::metric::Ton as MTon;
-
-use time::*;
-
-use temperature::celsius::*;
-use temperature::fahrenheit::*;
-use temperature::kelvin::*;
+//! Provides `Mul` and `Div`, the composite units formed by multiplying and dividing units.
+//!
+//! Every unit composes with every other unit, including another unit of the same dimension, and with composites.
+//! Dividing two units or composites of the same dimension gives a plain `f64` instead.
+//!
+//! ```
+//! use metric::composite::{Div, Mul};
+//! use metric::length::astronomical::{AU, Lightyear};
+//! use metric::length::metric::{Kilometer, Meter, Nanometer};
+//! use metric::mass::metric::{Gram, Kilogram};
+//! use metric::temperature::Celsius;
+//! use metric::time::{Day, Hour, Second, Year};
+//!
+//! let _: Div<Kilogram, Second> = Kilogram(3.0) / Second(1.0);
+//! let _: Mul<Gram, Celsius> = Gram(1.0) * Celsius(20.0);
+//! let _: Div<Lightyear, Year> = Lightyear(1.0) / Year(1.0);
+//! let _: Mul<AU, Day> = AU(1.0) * Day(1.0);
+//! let _: Div<Nanometer, Second> = Nanometer(500.0) / Second(1.0);
+//! let _: Mul<Kilometer, Meter> = Kilometer(1.0) * Meter(1.0);
+//! assert_eq!(Meter(500.0) / Kilometer(1.0), 0.5);
+//! assert_eq!((Meter(6.0) / Second(1.0)) / (Meter(3.0) / Second(1.0)), 2.0);
+//! assert_eq!(Meter(7200.0) / Hour(1.0), Meter(2.0) / Second(1.0));
+//! assert!(Kilometer(4.0) / Hour(1.0) > Meter(1.0) / Second(1.0));
+//! ```
+
+#[cfg(feature = "length")]
+use length::metric::Meter;
+#[cfg(feature = "mass")]
+use mass::metric::Kilogram;
+#[cfg(feature = "time")]
+use time::Second;
+#[cfg(feature = "temperature")]
+use temperature::Kelvin;
+#[cfg(feature = "angle")]
+use angle::Radian;
+#[cfg(feature = "solid_angle")]
+use solid_angle::Steradian;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use force::Newton;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use energy::Joule;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use power::Watt;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use pressure::Pascal;
+#[cfg(feature = "electrical")]
+use electrical::{Ampere, Coulomb, Farad, Henry, Ohm, Siemens, Volt};
 
 use core;
 use core::marker::PhantomData;
+#[cfg(any(feature = "std", feature = "libm"))]
+use math;
 use core::fmt;
+use dim::{Dimensioned, Dimensionless, IsDimensionless, Negate, Plus};
 
 pub trait Unit {
+    /// A value of zero in this unit.
+    const ZERO: Self;
+    /// A value of one in this unit.
+    const ONE: Self;
+
     fn new(val: f64) -> Self;
 
     //TODO: make inner() unsafe?
@@ -31,12 +71,346 @@ pub trait UnitName {
     fn write_unit_static(f: &mut fmt::Formatter) -> fmt::Result;
 }
 
+/// Relates a unit to the coherent SI unit of its dimension, e.g. `Foot` to `Meter`.
+///
+/// A value `x` of a unit corresponds to `x * SCALE + OFFSET` of its `Base`. Only temperature scales have an offset.
+/// Composites combine the scales of their parts and ignore offsets, since a temperature in a composite is a difference.
+pub trait Quantity: Unit + F64Repr {
+    /// The coherent SI unit of the same dimension.
+    type Base: Quantity;
+    /// The size of one of this unit, in `Base`.
+    const SCALE: f64;
+    /// The value of zero of this unit, in `Base`.
+    const OFFSET: f64 = 0.0;
+
+    /// Converts `self` into the SI base unit of its dimension.
+    #[inline(always)]
+    fn to_base(self) -> Self::Base {
+        Self::Base::new(self.inner() * Self::SCALE + Self::OFFSET)
+    }
+
+    /// Converts a value in the SI base unit of this dimension into this unit.
+    #[inline(always)]
+    fn from_base(base: Self::Base) -> Self {
+        Self::new((base.inner() - Self::OFFSET) / Self::SCALE)
+    }
+
+    /// Converts `self` into any other unit of the same dimension.
+    #[inline(always)]
+    fn convert<T>(self) -> T
+        where T: Quantity<Base = Self::Base>
+    {
+        convert_const(self)
+    }
+}
+
+/// Converts between two units of the same dimension, in `const` contexts.
+///
+/// ```
+/// use metric::composite::convert_const;
+/// use metric::length::imperial::Mile;
+/// use metric::length::metric::Kilometer;
+///
+/// const EARTH_RADIUS: Mile = convert_const(Kilometer(6371.0));
+/// ```
+#[inline(always)]
+pub const fn convert_const<T, U>(value: T) -> U
+    where T: Quantity,
+          U: Quantity<Base = T::Base>
+{
+    from_raw_value(convert_raw::<T, U>(raw_value(value)))
+}
+
+/// Converts a number of `T` into a number of `U`, whether or not the two have the same `Base`.
+///
+/// This is for units whose dimensions are already known to be the same, such as `Joule` and `Mul<Newton, Meter>`.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn convert_raw<T, U>(value: f64) -> f64
+    where T: Quantity,
+          U: Quantity
+{
+    //without offsets the ratio of the scales folds into a single constant, so the conversion is one multiplication
+    if T::OFFSET == 0.0 && U::OFFSET == 0.0 {
+        value * (T::SCALE / U::SCALE)
+    } else {
+        (value * T::SCALE + T::OFFSET - U::OFFSET) / U::SCALE
+    }
+}
+
+/// Marks units that are `#[repr(transparent)]` wrappers around a single `f64`, directly or through another unit.
+///
+/// This lets `const fn`s read and build values of any unit, which trait methods cannot yet do.
+#[doc(hidden)]
+pub unsafe trait F64Repr: Copy {}
+
+union F64Cast<T: Copy> {
+    value: T,
+    raw: f64,
+}
+
+/// Returns the `f64` stored in `value`, in `const` contexts.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn raw_value<T: F64Repr>(value: T) -> f64 {
+    unsafe { F64Cast { value }.raw }
+}
+
+/// Builds a unit from its stored `f64`, in `const` contexts.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn from_raw_value<T: F64Repr>(raw: f64) -> T {
+    unsafe { F64Cast { raw }.value }
+}
+
+/// Implemented by units and composites, and references to them, so operators can take any of them on the right.
+#[doc(hidden)]
+pub trait Operand: Copy {
+    type Unit: Quantity;
+
+    fn unit(self) -> Self::Unit;
+}
+
+/// Marks units that can be divided by another unit of the same dimension, giving a plain `f64`.
+///
+/// Temperature scales with an offset, such as `Celsius`, leave this out.
+#[doc(hidden)]
+pub trait DivSame: Quantity {}
+
+/// The result of comparing the dimensions of two base units.
+#[doc(hidden)]
+pub struct Same;
+
+/// The result of comparing the dimensions of two base units.
+#[doc(hidden)]
+pub struct Different;
+
+/// Compares the dimension of a base unit with that of `Rhs`, resolving to `Same` or `Different`.
+///
+/// Dimensions are compared by the exponent of each base dimension, so `Joule` has the same dimension as
+/// `Mul<Newton, Meter>` and `Div<Meter, Second>` the same as `Mul<Meter, Per<Second>>`.
+#[doc(hidden)]
+pub trait Compare<Rhs> {
+    type Result;
+}
+
+impl<A, B> Compare<B> for A
+    where A: Dimensioned,
+          B: Dimensioned,
+          B::Dim: Negate,
+          A::Dim: Plus<<B::Dim as Negate>::Output>,
+          <A::Dim as Plus<<B::Dim as Negate>::Output>>::Output: IsDimensionless
+{
+    type Result = <<A::Dim as Plus<<B::Dim as Negate>::Output>>::Output as IsDimensionless>::Output;
+}
+
+/// Divides `L` by `R`, giving `f64` for units of the same dimension and the composite `D` otherwise.
+#[doc(hidden)]
+pub trait Quotient<L, R, D> {
+    type Output;
+
+    fn quotient(l: L, r: R) -> Self::Output;
+}
+
+impl<L, R, D> Quotient<L, R, D> for Same
+    where L: DivSame,
+          R: Quantity
+{
+    type Output = f64;
+
+    #[inline(always)]
+    fn quotient(l: L, r: R) -> f64 {
+        l.inner() / convert_raw::<R, L>(r.inner())
+    }
+}
+
+impl<L, R, D> Quotient<L, R, D> for Different
+    where L: Unit,
+          R: Unit,
+          D: Unit
+{
+    type Output = D;
+
+    #[inline(always)]
+    fn quotient(l: L, r: R) -> D {
+        D::new(l.inner() / r.inner())
+    }
+}
+
+/// Divides one unit or composite by another, picking the `Quotient` from the dimensions of their base units.
+///
+/// `D` is the composite the quotient has when the dimensions differ, which composites pass to keep their own shape.
+#[doc(hidden)]
+pub trait Divide<R, D = Div<Self, R>> {
+    type Output;
+
+    fn divide(self, r: R) -> Self::Output;
+}
+
+impl<L, R, D> Divide<R, D> for L
+    where L: Quantity,
+          R: Quantity,
+          L::Base: Compare<R::Base>,
+          <L::Base as Compare<R::Base>>::Result: Quotient<L, R, D>
+{
+    type Output = <<L::Base as Compare<R::Base>>::Result as Quotient<L, R, D>>::Output;
+
+    #[inline(always)]
+    fn divide(self, r: R) -> Self::Output {
+        <<L::Base as Compare<R::Base>>::Result as Quotient<L, R, D>>::quotient(self, r)
+    }
+}
+
+/// Marks units that can be multiplied and divided by a scalar.
+///
+/// Temperature scales with an offset, such as `Celsius`, leave this out.
+#[doc(hidden)]
+pub trait Scalable: Unit {}
+
+/// The right-hand side of multiplying the unit `L`: a scalar keeps the unit, and any other unit gives a `Mul`.
+///
+/// Units implement `Mul` once, generically over this trait, so that units defined outside this crate don't have
+/// separate impls for scalars which would overlap with the one for other units.
+#[doc(hidden)]
+pub trait MulRhs<L> {
+    type Output;
+
+    fn mul_rhs(self, l: L) -> Self::Output;
+}
+
+impl<L, V> MulRhs<L> for V
+    where L: Unit,
+          V: Operand
+{
+    type Output = Mul<L, V::Unit>;
+
+    #[inline(always)]
+    fn mul_rhs(self, l: L) -> Mul<L, V::Unit> {
+        Mul(L::new(l.inner() * self.unit().inner()), PhantomData)
+    }
+}
+
+/// The right-hand side of dividing the unit `L`: a scalar keeps the unit, and any other unit gives its `Divide`.
+#[doc(hidden)]
+pub trait DivRhs<L> {
+    type Output;
+
+    fn div_rhs(self, l: L) -> Self::Output;
+}
+
+impl<L, V> DivRhs<L> for V
+    where V: Operand,
+          L: Divide<V::Unit>
+{
+    type Output = L::Output;
+
+    #[inline(always)]
+    fn div_rhs(self, l: L) -> L::Output {
+        l.divide(self.unit())
+    }
+}
+
+impl_for_scalars!(impl_scalar_rhs!());
+
+/// Inverts `Div<Self, U>`, giving `U` for `Per<U>` and `Div<U, Self>` otherwise.
+#[doc(hidden)]
+pub trait Invert<U>: Unit {
+    type Output: Unit;
+}
+
+impl<U> Invert<U> for One
+    where U: Unit
+{
+    type Output = U;
+}
+
+impl<T, U, V> Invert<V> for Mul<T, U>
+    where T: Unit,
+          V: Unit
+{
+    type Output = Div<V, Mul<T, U>>;
+}
+
+impl<T, U, V> Invert<V> for Div<T, U>
+    where T: Unit,
+          V: Unit
+{
+    type Output = Div<V, Div<T, U>>;
+}
+
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct Mul<T, U>(pub T, pub PhantomData<U>);
 
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct Div<T, U>(pub T, pub PhantomData<U>);
 
+/// The reciprocal of a unit, such as a rate in `Per<Second>` or a wavenumber in `Per<Meter>`.
+///
+/// Dividing a scalar by a unit or composite, or calling `recip` on it, gives its reciprocal, which takes part in
+/// composite arithmetic like any other `Div`.
+///
+/// ```
+/// use metric::composite::{One, Per};
+/// use metric::length::metric::Meter;
+/// use metric::temperature::Kelvin;
+/// use metric::time::{Hour, Second};
+///
+/// let rate: Per<Second> = 2.0 / Second(1.0);
+/// assert_eq!(rate.recip(), Second(0.5));
+/// assert_eq!(Per::<Hour>::from_const(rate).0, One(7200.0));
+///
+/// let expansion: Per<Kelvin> = Kelvin(1e5).recip();
+/// let strain = expansion.multiply(Kelvin(20.0));
+/// assert_eq!(Meter(10.0) * f64::from(strain), Meter(0.002));
+/// ```
+pub type Per<T> = Div<One, T>;
+
+/// The dimensionless unit, the numerator of `Per`.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct One(pub f64);
+
+impl_full_unit!(One);
+impl_quantity!(One => One, 1.0);
+impl_composite_base!(@operand One);
+
+impl UnitName for One {
+    fn get_unit(&self) -> &'static str {
+        "1"
+    }
+    fn get_unit_static() -> &'static str {
+        "1"
+    }
+    fn write_unit_static(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "1")
+    }
+}
+
+impl fmt::Debug for One {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<One> for f64 {
+    fn from(one: One) -> f64 {
+        one.0
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl One {
+    /// Raises `self` to the floating point power `n`.
+    ///
+    /// Only dimensionless values have this, since no unit can describe e.g. a length to the power `0.3`.
+    #[inline(always)]
+    pub fn powf(self, n: f64) -> One {
+        One(math::powf(self.0, n))
+    }
+}
+
 impl<T, U> UnitName for Mul<T, U>
     where T: Unit + UnitName,
           U: UnitName
@@ -101,6 +475,9 @@ impl<T, U> fmt::Debug for Div<T, U>
 impl<T, U> Unit for Mul<T, U>
     where T: Unit
 {
+    const ZERO: Self = Mul(T::ZERO, PhantomData);
+    const ONE: Self = Mul(T::ONE, PhantomData);
+
     #[inline(always)]
     fn new(val: f64) -> Mul<T, U> {
         Mul(T::new(val), PhantomData)
@@ -114,6 +491,9 @@ impl<T, U> Unit for Mul<T, U>
 impl<T, U> Unit for Div<T, U>
     where T: Unit
 {
+    const ZERO: Self = Div(T::ZERO, PhantomData);
+    const ONE: Self = Div(T::ONE, PhantomData);
+
     #[inline(always)]
     fn new(val: f64) -> Div<T, U> {
         Div(T::new(val), PhantomData)
@@ -125,6 +505,44 @@ impl<T, U> Unit for Div<T, U>
     }
 }
 
+impl<T, U> Mul<T, U>
+    where T: Unit
+{
+    /// A value of zero in this unit.
+    pub const ZERO: Self = Mul(T::ZERO, PhantomData);
+    /// A value of one in this unit.
+    pub const ONE: Self = Mul(T::ONE, PhantomData);
+}
+
+impl<T, U> Div<T, U>
+    where T: Unit
+{
+    /// A value of zero in this unit.
+    pub const ZERO: Self = Div(T::ZERO, PhantomData);
+    /// A value of one in this unit.
+    pub const ONE: Self = Div(T::ONE, PhantomData);
+}
+
+impl<T> core::iter::Product for Div<T, T>
+    where T: Unit
+{
+    fn product<I>(iter: I) -> Self
+        where I: Iterator<Item = Div<T, T>>
+    {
+        Div(T::new(iter.map(|ratio| ratio.inner()).product()), PhantomData)
+    }
+}
+
+impl<'a, T> core::iter::Product<&'a Div<T, T>> for Div<T, T>
+    where T: Unit
+{
+    fn product<I>(iter: I) -> Self
+        where I: Iterator<Item = &'a Div<T, T>>
+    {
+        Div(T::new(iter.map(|ratio| ratio.inner()).product()), PhantomData)
+    }
+}
+
 impl<T, U> Div<T, U>
     where T: Unit,
           U: Unit
@@ -135,6 +553,27 @@ impl<T, U> Div<T, U>
     }
 }
 
+impl<T, U> Div<T, U>
+    where T: Invert<U>,
+          U: Unit
+{
+    /// Returns the reciprocal of `self`, e.g. `Div<Second, Meter>` for `Div<Meter, Second>` and `T` for `Per<T>`.
+    #[inline(always)]
+    pub fn recip(self) -> T::Output {
+        Unit::new(1.0 / self.0.inner())
+    }
+}
+
+impl<T, U> Mul<T, U>
+    where T: Unit
+{
+    /// Returns the reciprocal of `self`.
+    #[inline(always)]
+    pub fn recip(self) -> Per<Mul<T, U>> {
+        Div(One(1.0 / self.inner()), PhantomData)
+    }
+}
+
 impl<T, U> Div<T, Mul<U, U>>
     where T: Unit,
           U: Unit
@@ -145,14 +584,45 @@ impl<T, U> Div<T, Mul<U, U>>
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T> Mul<T, T>
     where T: Copy + Unit
 {
     #[inline(always)]
     pub fn sqrt(&self) -> T {
-        extern crate std;
-        T::new(self.inner().sqrt())
+        T::new(math::sqrt(self.inner()))
+    }
+}
+
+impl_composite_math!(Mul);
+impl_composite_math!(Div);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Mul<T, Mul<T, T>>
+    where T: Copy + Unit
+{
+    #[inline(always)]
+    pub fn cbrt(&self) -> T {
+        T::new(math::cbrt(self.inner()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Div<T, T>
+    where T: Unit
+{
+    /// Raises the dimensionless ratio `self` to the floating point power `n`.
+    ///
+    /// ```
+    /// use metric::composite::Div;
+    /// use metric::temperature::Celsius;
+    ///
+    /// let ratio: Div<Celsius, Celsius> = Div::from_value(4.0);
+    /// assert_eq!(ratio.powf(0.5), Div::<Celsius, Celsius>::from_value(2.0));
+    /// ```
+    #[inline(always)]
+    pub fn powf(self, n: f64) -> Div<T, T> {
+        Div(T::new(math::powf(self.inner(), n)), PhantomData)
     }
 }
 
@@ -218,46 +688,42 @@ impl<T, U, W, X> core::ops::Mul<Div<T, U>> for Div<W, X>
 
 
 impl<T, U, W, X> core::ops::Div<Mul<T, U>> for Mul<W, X>
-    where W: Copy + Unit,
-          T: Copy + Unit
+    where Mul<W, X>: Divide<Mul<T, U>, Mul<W, Div<X, Mul<T, U>>>>
 {
-    type Output = Mul<W, Div<X, Mul<T, U>>>;
+    type Output = <Mul<W, X> as Divide<Mul<T, U>, Mul<W, Div<X, Mul<T, U>>>>>::Output;
 
     fn div(self, other: Mul<T, U>) -> Self::Output {
-        Mul(W::new(self.0.inner() / other.0.inner()), PhantomData)
+        self.divide(other)
     }
 }
 
 impl<T, U, W, X> core::ops::Div<Div<T, U>> for Mul<W, X>
-    where W: Copy + Unit,
-          T: Copy + Unit
+    where Mul<W, X>: Divide<Div<T, U>, Mul<W, Mul<X, Div<U, T>>>>
 {
-    type Output = Mul<W, Mul<X, Div<U, T>>>;
+    type Output = <Mul<W, X> as Divide<Div<T, U>, Mul<W, Mul<X, Div<U, T>>>>>::Output;
 
     fn div(self, other: Div<T, U>) -> Self::Output {
-        Mul(W::new(self.0.inner() / other.0.inner()), PhantomData)
+        self.divide(other)
     }
 }
 
 impl<T, U, W, X> core::ops::Div<Mul<T, U>> for Div<W, X>
-    where W: Copy + Unit,
-          T: Copy + Unit
+    where Div<W, X>: Divide<Mul<T, U>, Div<W, Mul<X, Mul<T, U>>>>
 {
-    type Output = Div<W, Mul<X, Mul<T, U>>>;
+    type Output = <Div<W, X> as Divide<Mul<T, U>, Div<W, Mul<X, Mul<T, U>>>>>::Output;
 
     fn div(self, other: Mul<T, U>) -> Self::Output {
-        Div(W::new(self.0.inner() / other.0.inner()), PhantomData)
+        self.divide(other)
     }
 }
 
 impl<T, U, W, X> core::ops::Div<Div<T, U>> for Div<W, X>
-    where W: Copy + Unit,
-          T: Copy + Unit
+    where Div<W, X>: Divide<Div<T, U>, Mul<W, Div<U, Mul<T, X>>>>
 {
-    type Output = Mul<W, Div<U, Mul<T, X>>>;
+    type Output = <Div<W, X> as Divide<Div<T, U>, Mul<W, Div<U, Mul<T, X>>>>>::Output;
 
     fn div(self, other: Div<T, U>) -> Self::Output {
-        Mul(W::new(self.0.inner() / other.0.inner()), PhantomData)
+        self.divide(other)
     }
 }
 
@@ -304,592 +770,131 @@ impl<T, U> core::ops::Add for Div<T, U>
 }
 
 
-impl<T, U> core::ops::Mul<f64> for Mul<T, U>
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Mul<T, U>;
-
-    fn mul(self, other: f64) -> Self::Output {
-        Mul(self.0 * other, self.1)
-    }
-}
-impl<T, U> core::ops::Mul<Mul<T, U>> for f64
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Mul<T, U>;
-
-    fn mul(self, other: Mul<T, U>) -> Self::Output {
-        Mul(other.0 * self, other.1)
-    }
-}
-impl<T, U> core::ops::Mul<i64> for Mul<T, U>
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Mul<T, U>;
+impl_composite_ops!(Mul);
+impl_composite_ops!(Div);
 
-    fn mul(self, other: i64) -> Self::Output {
-        Mul(self.0 * other as f64, self.1)
-    }
-}
-impl<T, U> core::ops::Mul<Mul<T, U>> for i64
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Mul<T, U>;
+impl_composite_partial_ord!(Mul);
+impl_composite_partial_ord!(Div);
 
-    fn mul(self, other: Mul<T, U>) -> Self::Output {
-        Mul(other.0 * self as f64, other.1)
-    }
-}
+impl_composite_approx_eq!(Mul);
+impl_composite_approx_eq!(Div);
 
-impl<T, U> core::ops::MulAssign<f64> for Mul<T, U>
-    where T: core::ops::MulAssign<f64>
-{
-    fn mul_assign(&mut self, other: f64) {
-        self.0 *= other;
-    }
-}
-impl<T, U> core::ops::MulAssign<i64> for Mul<T, U>
-    where T: core::ops::MulAssign<f64>
-{
-    fn mul_assign(&mut self, other: i64) {
-        self.0 *= other as f64;
-    }
-}
+impl_composite_quantity!(Mul, *);
+impl_composite_quantity!(Div, /);
 
-impl<T, U> core::ops::Div<f64> for Mul<T, U>
-    where T: core::ops::Div<f64, Output = T>
-{
-    type Output = Mul<T, U>;
+impl_composite_scalar_ops!(Mul);
+impl_composite_scalar_ops!(Div);
 
-    fn div(self, other: f64) -> Self::Output {
-        Mul(self.0 / other, self.1)
-    }
-}
-impl<T, U> core::ops::Div<i64> for Mul<T, U>
-    where T: core::ops::Div<f64, Output = T>
+impl<T, U> Operand for Mul<T, U>
+    where T: Quantity,
+          U: Quantity
 {
-    type Output = Mul<T, U>;
+    type Unit = Mul<T, U>;
 
-    fn div(self, other: i64) -> Self::Output {
-        Mul(self.0 / other as f64, self.1)
+    #[inline(always)]
+    fn unit(self) -> Self::Unit {
+        self
     }
 }
 
-impl<T, U> core::ops::DivAssign<f64> for Mul<T, U>
-    where T: core::ops::DivAssign<f64>
+impl<T, U> Operand for &Mul<T, U>
+    where T: Quantity,
+          U: Quantity
 {
-    fn div_assign(&mut self, other: f64) {
-        self.0 /= other;
-    }
-}
-impl<T, U> core::ops::DivAssign<i64> for Mul<T, U>
-    where T: core::ops::DivAssign<f64>
-{
-    fn div_assign(&mut self, other: i64) {
-        self.0 /= other as f64;
-    }
-}
+    type Unit = Mul<T, U>;
 
-
-
-impl<T, U> core::ops::Mul<f64> for Div<T, U>
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Div<T, U>;
-
-    fn mul(self, other: f64) -> Self::Output {
-        Div(self.0 * other, self.1)
-    }
-}
-impl<T, U> core::ops::Mul<Div<T, U>> for f64
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Div<T, U>;
-
-    fn mul(self, other: Div<T, U>) -> Self::Output {
-        Div(other.0 * self, other.1)
+    #[inline(always)]
+    fn unit(self) -> Self::Unit {
+        *self
     }
 }
-impl<T, U> core::ops::Mul<i64> for Div<T, U>
-    where T: core::ops::Mul<f64, Output = T>
-{
-    type Output = Div<T, U>;
 
-    fn mul(self, other: i64) -> Self::Output {
-        Div(self.0 * other as f64, self.1)
-    }
-}
-impl<T, U> core::ops::Mul<Div<T, U>> for i64
-    where T: core::ops::Mul<f64, Output = T>
+impl<T, U> Operand for Div<T, U>
+    where T: Quantity,
+          U: Quantity
 {
-    type Output = Div<T, U>;
-
-    fn mul(self, other: Div<T, U>) -> Self::Output {
-        Div(other.0 * self as f64, other.1)
-    }
-}
+    type Unit = Div<T, U>;
 
-impl<T, U> core::ops::MulAssign<f64> for Div<T, U>
-    where T: core::ops::MulAssign<f64>
-{
-    fn mul_assign(&mut self, other: f64) {
-        self.0 *= other;
-    }
-}
-impl<T, U> core::ops::MulAssign<i64> for Div<T, U>
-    where T: core::ops::MulAssign<f64>
-{
-    fn mul_assign(&mut self, other: i64) {
-        self.0 *= other as f64;
+    #[inline(always)]
+    fn unit(self) -> Self::Unit {
+        self
     }
 }
 
-impl<T, U> core::ops::Div<f64> for Div<T, U>
-    where T: core::ops::Div<f64, Output = T>
+impl<T, U> Operand for &Div<T, U>
+    where T: Quantity,
+          U: Quantity
 {
-    type Output = Div<T, U>;
+    type Unit = Div<T, U>;
 
-    fn div(self, other: f64) -> Self::Output {
-        Div(self.0 / other, self.1)
-    }
-}
-impl<T, U> core::ops::Div<i64> for Div<T, U>
-    where T: core::ops::Div<f64, Output = T>
-{
-    type Output = Div<T, U>;
-
-    fn div(self, other: i64) -> Self::Output {
-        Div(self.0 / other as f64, self.1)
-    }
-}
-
-impl<T, U> core::ops::DivAssign<f64> for Div<T, U>
-    where T: core::ops::DivAssign<f64>
-{
-    fn div_assign(&mut self, other: f64) {
-        self.0 /= other;
-    }
-}
-impl<T, U> core::ops::DivAssign<i64> for Div<T, U>
-    where T: core::ops::DivAssign<f64>
-{
-    fn div_assign(&mut self, other: i64) {
-        self.0 /= other as f64;
-    }
-}
-
-impl_composite_base!(AU);
-impl_composite_base!(Lightyear);
-impl_composite_base!(Kilometer);
-impl_composite_base!(Meter);
-impl_composite_base!(Centimeter);
-impl_composite_base!(Millimeter);
-impl_composite_base!(Micrometer);
-impl_composite_base!(Picometer);
-impl_composite_base!(Femtometer);
-impl_composite_base!(Inch);
-impl_composite_base!(Foot);
-impl_composite_base!(Yard);
-impl_composite_base!(Mile);
-impl_composite_base!(Fahrenheit);
-impl_composite_base!(Celsius);
-impl_composite_base!(Kelvin);
-impl_composite_base!(Ounce);
-impl_composite_base!(Pound);
-impl_composite_base!(ITon);
-impl_composite_base!(MTon);
-impl_composite_base!(Kilogram);
-impl_composite_base!(Gram);
-impl_composite_base!(Centigram);
-impl_composite_base!(Milligram);
-impl_composite_base!(Second);
-impl_composite_base!(Minute);
-impl_composite_base!(Hour);
-impl_composite_base!(Day);
-impl_composite_base!(Year);
-impl_composite_base!(Decade);
-impl_composite_base!(Century);
-impl_composite_base!(Millennium);
-
-impl_composite!(Kilometer, Fahrenheit);
-impl_composite!(Kilometer, Celsius);
-impl_composite!(Kilometer, Kelvin);
-impl_composite!(Kilometer, Ounce);
-impl_composite!(Kilometer, Pound);
-impl_composite!(Kilometer, ITon);
-impl_composite!(Kilometer, MTon);
-impl_composite!(Kilometer, Kilogram);
-impl_composite!(Kilometer, Gram);
-impl_composite!(Kilometer, Centigram);
-impl_composite!(Kilometer, Milligram);
-impl_composite!(Kilometer, Second);
-impl_composite!(Kilometer, Minute);
-impl_composite!(Kilometer, Hour);
-impl_composite!(Kilometer, Day);
-impl_composite!(Kilometer, Year);
-impl_composite!(Kilometer, Decade);
-impl_composite!(Kilometer, Century);
-impl_composite!(Kilometer, Millennium);
-
-impl_composite!(Meter, Fahrenheit);
-impl_composite!(Meter, Celsius);
-impl_composite!(Meter, Kelvin);
-impl_composite!(Meter, Ounce);
-impl_composite!(Meter, Pound);
-impl_composite!(Meter, ITon);
-impl_composite!(Meter, MTon);
-impl_composite!(Meter, Kilogram);
-impl_composite!(Meter, Gram);
-impl_composite!(Meter, Centigram);
-impl_composite!(Meter, Milligram);
-impl_composite!(Meter, Second);
-impl_composite!(Meter, Minute);
-impl_composite!(Meter, Hour);
-impl_composite!(Meter, Day);
-impl_composite!(Meter, Year);
-impl_composite!(Meter, Decade);
-impl_composite!(Meter, Century);
-impl_composite!(Meter, Millennium);
-
-impl_composite!(Centimeter, Fahrenheit);
-impl_composite!(Centimeter, Celsius);
-impl_composite!(Centimeter, Kelvin);
-impl_composite!(Centimeter, Ounce);
-impl_composite!(Centimeter, Pound);
-impl_composite!(Centimeter, ITon);
-impl_composite!(Centimeter, MTon);
-impl_composite!(Centimeter, Kilogram);
-impl_composite!(Centimeter, Gram);
-impl_composite!(Centimeter, Centigram);
-impl_composite!(Centimeter, Milligram);
-impl_composite!(Centimeter, Second);
-impl_composite!(Centimeter, Minute);
-impl_composite!(Centimeter, Hour);
-impl_composite!(Centimeter, Day);
-impl_composite!(Centimeter, Year);
-impl_composite!(Centimeter, Decade);
-impl_composite!(Centimeter, Century);
-impl_composite!(Centimeter, Millennium);
-
-impl_composite!(Millimeter, Fahrenheit);
-impl_composite!(Millimeter, Celsius);
-impl_composite!(Millimeter, Kelvin);
-impl_composite!(Millimeter, Ounce);
-impl_composite!(Millimeter, Pound);
-impl_composite!(Millimeter, ITon);
-impl_composite!(Millimeter, MTon);
-impl_composite!(Millimeter, Kilogram);
-impl_composite!(Millimeter, Gram);
-impl_composite!(Millimeter, Centigram);
-impl_composite!(Millimeter, Milligram);
-impl_composite!(Millimeter, Second);
-impl_composite!(Millimeter, Minute);
-impl_composite!(Millimeter, Hour);
-impl_composite!(Millimeter, Day);
-impl_composite!(Millimeter, Year);
-impl_composite!(Millimeter, Decade);
-impl_composite!(Millimeter, Century);
-impl_composite!(Millimeter, Millennium);
-
-impl_composite!(Micrometer, Fahrenheit);
-impl_composite!(Micrometer, Celsius);
-impl_composite!(Micrometer, Kelvin);
-impl_composite!(Micrometer, Ounce);
-impl_composite!(Micrometer, Pound);
-impl_composite!(Micrometer, ITon);
-impl_composite!(Micrometer, MTon);
-impl_composite!(Micrometer, Kilogram);
-impl_composite!(Micrometer, Gram);
-impl_composite!(Micrometer, Centigram);
-impl_composite!(Micrometer, Milligram);
-impl_composite!(Micrometer, Second);
-impl_composite!(Micrometer, Minute);
-impl_composite!(Micrometer, Hour);
-impl_composite!(Micrometer, Day);
-impl_composite!(Micrometer, Year);
-impl_composite!(Micrometer, Decade);
-impl_composite!(Micrometer, Century);
-impl_composite!(Micrometer, Millennium);
-
-impl_composite!(Picometer, Fahrenheit);
-impl_composite!(Picometer, Celsius);
-impl_composite!(Picometer, Kelvin);
-impl_composite!(Picometer, Ounce);
-impl_composite!(Picometer, Pound);
-impl_composite!(Picometer, ITon);
-impl_composite!(Picometer, MTon);
-impl_composite!(Picometer, Kilogram);
-impl_composite!(Picometer, Gram);
-impl_composite!(Picometer, Centigram);
-impl_composite!(Picometer, Milligram);
-impl_composite!(Picometer, Second);
-impl_composite!(Picometer, Minute);
-impl_composite!(Picometer, Hour);
-impl_composite!(Picometer, Day);
-impl_composite!(Picometer, Year);
-impl_composite!(Picometer, Decade);
-impl_composite!(Picometer, Century);
-impl_composite!(Picometer, Millennium);
-
-impl_composite!(Femtometer, Fahrenheit);
-impl_composite!(Femtometer, Celsius);
-impl_composite!(Femtometer, Kelvin);
-impl_composite!(Femtometer, Ounce);
-impl_composite!(Femtometer, Pound);
-impl_composite!(Femtometer, ITon);
-impl_composite!(Femtometer, MTon);
-impl_composite!(Femtometer, Kilogram);
-impl_composite!(Femtometer, Gram);
-impl_composite!(Femtometer, Centigram);
-impl_composite!(Femtometer, Milligram);
-impl_composite!(Femtometer, Second);
-impl_composite!(Femtometer, Minute);
-impl_composite!(Femtometer, Hour);
-impl_composite!(Femtometer, Day);
-impl_composite!(Femtometer, Year);
-impl_composite!(Femtometer, Decade);
-impl_composite!(Femtometer, Century);
-impl_composite!(Femtometer, Millennium);
-
-impl_composite!(AU, Fahrenheit);
-impl_composite!(AU, Celsius);
-impl_composite!(AU, Kelvin);
-impl_composite!(AU, Ounce);
-impl_composite!(AU, Pound);
-impl_composite!(AU, ITon);
-impl_composite!(AU, MTon);
-impl_composite!(AU, Kilogram);
-impl_composite!(AU, Gram);
-impl_composite!(AU, Centigram);
-impl_composite!(AU, Milligram);
-impl_composite!(AU, Second);
-impl_composite!(AU, Minute);
-impl_composite!(AU, Hour);
-impl_composite!(AU, Day);
-impl_composite!(AU, Year);
-impl_composite!(AU, Decade);
-impl_composite!(AU, Century);
-impl_composite!(AU, Millennium);
-
-impl_composite!(Lightyear, Fahrenheit);
-impl_composite!(Lightyear, Celsius);
-impl_composite!(Lightyear, Kelvin);
-impl_composite!(Lightyear, Ounce);
-impl_composite!(Lightyear, Pound);
-impl_composite!(Lightyear, ITon);
-impl_composite!(Lightyear, MTon);
-impl_composite!(Lightyear, Kilogram);
-impl_composite!(Lightyear, Gram);
-impl_composite!(Lightyear, Centigram);
-impl_composite!(Lightyear, Milligram);
-impl_composite!(Lightyear, Second);
-impl_composite!(Lightyear, Minute);
-impl_composite!(Lightyear, Hour);
-impl_composite!(Lightyear, Day);
-impl_composite!(Lightyear, Year);
-impl_composite!(Lightyear, Decade);
-impl_composite!(Lightyear, Century);
-impl_composite!(Lightyear, Millennium);
-
-impl_composite!(Inch, Fahrenheit);
-impl_composite!(Inch, Celsius);
-impl_composite!(Inch, Kelvin);
-impl_composite!(Inch, Ounce);
-impl_composite!(Inch, Pound);
-impl_composite!(Inch, ITon);
-impl_composite!(Inch, MTon);
-impl_composite!(Inch, Kilogram);
-impl_composite!(Inch, Gram);
-impl_composite!(Inch, Centigram);
-impl_composite!(Inch, Milligram);
-impl_composite!(Inch, Second);
-impl_composite!(Inch, Minute);
-impl_composite!(Inch, Hour);
-impl_composite!(Inch, Day);
-impl_composite!(Inch, Year);
-impl_composite!(Inch, Decade);
-impl_composite!(Inch, Century);
-impl_composite!(Inch, Millennium);
-
-impl_composite!(Foot, Fahrenheit);
-impl_composite!(Foot, Celsius);
-impl_composite!(Foot, Kelvin);
-impl_composite!(Foot, Ounce);
-impl_composite!(Foot, Pound);
-impl_composite!(Foot, ITon);
-impl_composite!(Foot, MTon);
-impl_composite!(Foot, Kilogram);
-impl_composite!(Foot, Gram);
-impl_composite!(Foot, Centigram);
-impl_composite!(Foot, Milligram);
-impl_composite!(Foot, Second);
-impl_composite!(Foot, Minute);
-impl_composite!(Foot, Hour);
-impl_composite!(Foot, Day);
-impl_composite!(Foot, Year);
-impl_composite!(Foot, Decade);
-impl_composite!(Foot, Century);
-impl_composite!(Foot, Millennium);
-
-impl_composite!(Yard, Fahrenheit);
-impl_composite!(Yard, Celsius);
-impl_composite!(Yard, Kelvin);
-impl_composite!(Yard, Ounce);
-impl_composite!(Yard, Pound);
-impl_composite!(Yard, ITon);
-impl_composite!(Yard, MTon);
-impl_composite!(Yard, Kilogram);
-impl_composite!(Yard, Gram);
-impl_composite!(Yard, Centigram);
-impl_composite!(Yard, Milligram);
-impl_composite!(Yard, Second);
-impl_composite!(Yard, Minute);
-impl_composite!(Yard, Hour);
-impl_composite!(Yard, Day);
-impl_composite!(Yard, Year);
-impl_composite!(Yard, Decade);
-impl_composite!(Yard, Century);
-impl_composite!(Yard, Millennium);
-
-impl_composite!(Mile, Fahrenheit);
-impl_composite!(Mile, Celsius);
-impl_composite!(Mile, Kelvin);
-impl_composite!(Mile, Ounce);
-impl_composite!(Mile, Pound);
-impl_composite!(Mile, ITon);
-impl_composite!(Mile, MTon);
-impl_composite!(Mile, Kilogram);
-impl_composite!(Mile, Gram);
-impl_composite!(Mile, Centigram);
-impl_composite!(Mile, Milligram);
-impl_composite!(Mile, Second);
-impl_composite!(Mile, Minute);
-impl_composite!(Mile, Hour);
-impl_composite!(Mile, Day);
-impl_composite!(Mile, Year);
-impl_composite!(Mile, Decade);
-impl_composite!(Mile, Century);
-impl_composite!(Mile, Millennium);
-
-impl_composite!(Fahrenheit, Ounce);
-impl_composite!(Fahrenheit, Pound);
-impl_composite!(Fahrenheit, ITon);
-impl_composite!(Fahrenheit, MTon);
-impl_composite!(Fahrenheit, Kilogram);
-impl_composite!(Fahrenheit, Gram);
-impl_composite!(Fahrenheit, Centigram);
-impl_composite!(Fahrenheit, Milligram);
-impl_composite!(Fahrenheit, Second);
-impl_composite!(Fahrenheit, Minute);
-impl_composite!(Fahrenheit, Hour);
-impl_composite!(Fahrenheit, Day);
-impl_composite!(Fahrenheit, Year);
-impl_composite!(Fahrenheit, Decade);
-impl_composite!(Fahrenheit, Century);
-impl_composite!(Fahrenheit, Millennium);
-
-impl_composite!(Celsius, Ounce);
-impl_composite!(Celsius, Pound);
-impl_composite!(Celsius, ITon);
-impl_composite!(Celsius, MTon);
-impl_composite!(Celsius, Kilogram);
-impl_composite!(Celsius, Gram);
-impl_composite!(Celsius, Centigram);
-impl_composite!(Celsius, Milligram);
-impl_composite!(Celsius, Second);
-impl_composite!(Celsius, Minute);
-impl_composite!(Celsius, Hour);
-impl_composite!(Celsius, Day);
-impl_composite!(Celsius, Year);
-impl_composite!(Celsius, Decade);
-impl_composite!(Celsius, Century);
-impl_composite!(Celsius, Millennium);
-
-impl_composite!(Kelvin, Ounce);
-impl_composite!(Kelvin, Pound);
-impl_composite!(Kelvin, ITon);
-impl_composite!(Kelvin, MTon);
-impl_composite!(Kelvin, Kilogram);
-impl_composite!(Kelvin, Gram);
-impl_composite!(Kelvin, Centigram);
-impl_composite!(Kelvin, Milligram);
-impl_composite!(Kelvin, Second);
-impl_composite!(Kelvin, Minute);
-impl_composite!(Kelvin, Hour);
-impl_composite!(Kelvin, Day);
-impl_composite!(Kelvin, Year);
-impl_composite!(Kelvin, Decade);
-impl_composite!(Kelvin, Century);
-impl_composite!(Kelvin, Millennium);
-
-impl_composite!(Ounce, Second);
-impl_composite!(Ounce, Minute);
-impl_composite!(Ounce, Hour);
-impl_composite!(Ounce, Day);
-impl_composite!(Ounce, Year);
-impl_composite!(Ounce, Decade);
-impl_composite!(Ounce, Century);
-impl_composite!(Ounce, Millennium);
-
-impl_composite!(Pound, Second);
-impl_composite!(Pound, Minute);
-impl_composite!(Pound, Hour);
-impl_composite!(Pound, Day);
-impl_composite!(Pound, Year);
-impl_composite!(Pound, Decade);
-impl_composite!(Pound, Century);
-impl_composite!(Pound, Millennium);
-
-impl_composite!(ITon, Second);
-impl_composite!(ITon, Minute);
-impl_composite!(ITon, Hour);
-impl_composite!(ITon, Day);
-impl_composite!(ITon, Year);
-impl_composite!(ITon, Decade);
-impl_composite!(ITon, Century);
-impl_composite!(ITon, Millennium);
-
-impl_composite!(MTon, Second);
-impl_composite!(MTon, Minute);
-impl_composite!(MTon, Hour);
-impl_composite!(MTon, Day);
-impl_composite!(MTon, Year);
-impl_composite!(MTon, Decade);
-impl_composite!(MTon, Century);
-impl_composite!(MTon, Millennium);
-
-impl_composite!(Kilogram, Second);
-impl_composite!(Kilogram, Minute);
-impl_composite!(Kilogram, Hour);
-impl_composite!(Kilogram, Day);
-impl_composite!(Kilogram, Year);
-impl_composite!(Kilogram, Decade);
-impl_composite!(Kilogram, Century);
-impl_composite!(Kilogram, Millennium);
-
-impl_composite!(Gram, Second);
-impl_composite!(Gram, Minute);
-impl_composite!(Gram, Hour);
-impl_composite!(Gram, Day);
-impl_composite!(Gram, Year);
-impl_composite!(Gram, Decade);
-impl_composite!(Gram, Century);
-impl_composite!(Gram, Millennium);
-
-impl_composite!(Centigram, Second);
-impl_composite!(Centigram, Minute);
-impl_composite!(Centigram, Hour);
-impl_composite!(Centigram, Day);
-impl_composite!(Centigram, Year);
-impl_composite!(Centigram, Decade);
-impl_composite!(Centigram, Century);
-impl_composite!(Centigram, Millennium);
-
-impl_composite!(Milligram, Second);
-impl_composite!(Milligram, Minute);
-impl_composite!(Milligram, Hour);
-impl_composite!(Milligram, Day);
-impl_composite!(Milligram, Year);
-impl_composite!(Milligram, Decade);
-impl_composite!(Milligram, Century);
-impl_composite!(Milligram, Millennium);
+    #[inline(always)]
+    fn unit(self) -> Self::Unit {
+        *self
+    }
+}
+
+impl<T, U> DivSame for Mul<T, U>
+    where T: Quantity,
+          U: Quantity
+{}
+
+impl<T, U> DivSame for Div<T, U>
+    where T: Quantity,
+          U: Quantity
+{}
+
+impl<T, U> Dimensioned for Mul<T, U>
+    where T: Dimensioned,
+          U: Dimensioned,
+          T::Dim: Plus<U::Dim>
+{
+    type Dim = <T::Dim as Plus<U::Dim>>::Output;
+}
+
+impl<T, U> Dimensioned for Div<T, U>
+    where T: Dimensioned,
+          U: Dimensioned,
+          U::Dim: Negate,
+          T::Dim: Plus<<U::Dim as Negate>::Output>
+{
+    type Dim = <T::Dim as Plus<<U::Dim as Negate>::Output>>::Output;
+}
+
+impl Dimensioned for One {
+    type Dim = Dimensionless;
+}
+
+#[cfg(feature = "length")]
+impl_base_dimension!(Meter = Length);
+#[cfg(feature = "mass")]
+impl_base_dimension!(Kilogram = Mass);
+#[cfg(feature = "time")]
+impl_base_dimension!(Second = Time);
+#[cfg(feature = "temperature")]
+impl_base_dimension!(Kelvin = Temperature);
+#[cfg(feature = "angle")]
+impl_base_dimension!(Radian = Angle);
+#[cfg(feature = "solid_angle")]
+impl_base_dimension!(Steradian = SolidAngle);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Ampere = Current);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_base_dimension!(Newton => Mul<Kilogram, Div<Meter, Mul<Second, Second>>>);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_base_dimension!(Joule => Mul<Newton, Meter>);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_base_dimension!(Watt => Div<Joule, Second>);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_base_dimension!(Pascal => Div<Newton, Mul<Meter, Meter>>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Volt => Div<Watt, Ampere>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Ohm => Div<Volt, Ampere>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Siemens => Div<Ampere, Volt>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Coulomb => Mul<Ampere, Second>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Farad => Div<Coulomb, Volt>);
+#[cfg(feature = "electrical")]
+impl_base_dimension!(Henry => Mul<Ohm, Second>);
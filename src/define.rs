@@ -0,0 +1,506 @@
+//! Provides `define_unit!` and `define_dimension!`, for declaring new units and dimensions that work with the units
+//! and composites of this crate.
+
+/// Declares a new unit as a multiple of an existing one.
+///
+/// The new unit gets the same operators, formatting, `const fn` helpers and composite support as the units that
+/// ship with this crate. The factor is the size of one of the new unit in the reference unit. The dimension is either:
+///
+/// * the name of a built-in module: `length`, `mass`, `time`, `temperature`, `angle`, `solid_angle`, `force`,
+///   `energy`, `power` or `pressure`. The unit then converts to and from every unit of that module.
+/// * the trait of any other dimension, such as `Voltage` from the `dimension` module or one declared with
+///   `define_dimension!`. The unit then converts to and from its reference unit.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::length::imperial::Mile;
+/// use metric::length::metric::Meter;
+/// use metric::time::Hour;
+///
+/// define_unit! {
+///     /// One eighth of a mile.
+///     pub struct Furlong;
+///     plural: Furlongs;
+///     symbol: "fur";
+///     dimension: length;
+///     factor: 201.168 Meter;
+/// }
+///
+/// fn main() {
+///     let race = Furlong(8.0) + Mile(1.0);
+///     assert_eq!(Meter::from(race).0.round(), 3219.0);
+///     println!("{:?}", race / Hour(0.1));
+/// }
+/// ```
+///
+/// Units declared this way convert to each other through `Quantity::convert` rather than `From`.
+///
+/// The operators follow the dimension, as they do for the built-in units. A unit of `temperature` is a scale like
+/// `Celsius`, keeping the offset of its reference, so it can be added to and compared but not scaled or divided. Every
+/// other unit must have no offset, which is checked at compile time, and units of `force`, `energy`, `power` and
+/// `pressure` also convert to and from the composites of their dimension.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::length::metric::Meter;
+/// use metric::mass::metric::Kilogram;
+/// use metric::temperature::{Celsius, Kelvin};
+/// use metric::time::Second;
+///
+/// define_unit! {
+///     /// A degree on the Réaumur scale, which puts the boiling point of water at 80.
+///     pub struct Reaumur;
+///     symbol: "°Ré";
+///     dimension: temperature;
+///     factor: 1.25 Celsius;
+/// }
+///
+/// define_unit! {
+///     /// A thousand newtons.
+///     pub struct Sthene;
+///     symbol: "sn";
+///     dimension: force;
+///     factor: 1000.0 metric::force::Newton;
+/// }
+///
+/// fn main() {
+///     assert_eq!(Kelvin::from(Reaumur(0.0)), Kelvin(273.15));
+///     assert!(Reaumur(80.0) > Celsius(99.0));
+///     assert_eq!(Sthene::from(Kilogram(500.0) * (Meter(4.0) / (Second(1.0) * Second(1.0)))), Sthene(2.0));
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::temperature::Celsius;
+///
+/// define_unit! {
+///     pub struct Reaumur;
+///     symbol: "°Ré";
+///     dimension: temperature;
+///     factor: 1.25 Celsius;
+/// }
+///
+/// fn main() {
+///     let _ = Reaumur(80.0) * 2.0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_unit {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+        $(plural: $($plural:ident),+;)?
+        symbol: $symbol:literal;
+        dimension: $dimension:ident;
+        factor: $factor:tt $reference:ty;
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        #[repr(transparent)]
+        $vis struct $name(pub f64);
+        $($($vis type $plural = $name;)+)?
+
+        $crate::__define_unit_ops!($dimension, $name);
+        $crate::impl_unit_debug!($name => $symbol);
+        $crate::impl_quantity!($name => <$reference as $crate::composite::Quantity>::Base,
+                               $factor * <$reference as $crate::composite::Quantity>::SCALE,
+                               <$reference as $crate::composite::Quantity>::OFFSET);
+        $crate::impl_composite_base!($name);
+        $crate::__define_unit_dimension!($dimension, $name, $reference);
+    }
+}
+
+/// Declares a new base dimension, along with the trait that marks its units and its base unit.
+///
+/// The base unit composes with the built-in units like any other, so rates such as `Div<Widget, Hour>` can be
+/// formed and converted, while adding a `Widget` to an `Hour` is still a compile error. Further units of the dimension
+/// are declared with `define_unit!`, naming the trait as their dimension. Units of two different user-defined
+/// dimensions can be multiplied and divided like any other pair of dimensions, and dividing two units of the same
+/// dimension gives a plain `f64`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::composite::Div;
+/// use metric::time::{Hour, Second};
+///
+/// define_dimension! {
+///     /// Implemented by every unit that counts widgets.
+///     pub trait Production;
+///     /// A single widget.
+///     pub struct Widget;
+///     plural: Widgets;
+///     symbol: "widget";
+/// }
+///
+/// define_unit! {
+///     /// A thousand widgets.
+///     pub struct Kilowidget;
+///     symbol: "kwidget";
+///     dimension: Production;
+///     factor: 1000.0 Widget;
+/// }
+///
+/// define_dimension! {
+///     /// Implemented by every unit that counts pallets.
+///     pub trait Storage;
+///     /// A single pallet.
+///     pub struct Pallet;
+///     plural: Pallets;
+///     symbol: "pallet";
+/// }
+///
+/// fn total<P: Production>(batches: &[P]) -> Widget {
+///     batches.iter().map(|&b| b.to_base()).sum()
+/// }
+///
+/// fn main() {
+///     let rate = Kilowidget(3.6) / Hour(1.0);
+///     let per_second: Div<Widget, Second> = Div::from_const(rate);
+///     assert_eq!(per_second.0, Widget(1.0));
+///     assert_eq!(total(&[Kilowidget(1.0), Kilowidget(0.5)]), Widget(1500.0));
+///
+///     let per_pallet = Kilowidget(1.2) / Pallet(1.0);
+///     assert_eq!(Div::<Widget, Pallet>::from_const(per_pallet).0, Widget(1200.0));
+///     assert_eq!(Kilowidget(1.5) / Widget(500.0), 3.0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_dimension {
+    (
+        $(#[$trait_attr:meta])*
+        $trait_vis:vis trait $dimension:ident;
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+        $(plural: $($plural:ident),+;)?
+        symbol: $symbol:literal;
+    ) => {
+        $(#[$trait_attr])*
+        $trait_vis trait $dimension: $crate::composite::Quantity<Base = $name> {}
+
+        impl<T> $dimension for T where T: $crate::composite::Quantity<Base = $name> {}
+
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        #[repr(transparent)]
+        $vis struct $name(pub f64);
+        $($($vis type $plural = $name;)+)?
+
+        $crate::impl_full_unit!($name);
+        $crate::impl_unit_debug!($name => $symbol);
+        $crate::impl_quantity!($name => $name, 1.0);
+        $crate::impl_composite_base!($name);
+        $crate::impl_base_dimension!($name, concat!(module_path!(), "::", stringify!($dimension)));
+        impl $crate::prefix::Coherent for $name {}
+    }
+}
+
+//gives the new unit the operators of its dimension. temperatures are scales with an offset, like `Celsius`, so they
+//add and compare but don't scale or divide. every other unit must have no offset and gets the full set, and units of
+//the derived dimensions also convert to and from their composites
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __define_unit_ops {
+    (@full $name:ident) => {
+        const _: () = assert!(<$name as $crate::composite::Quantity>::OFFSET == 0.0,
+                              "only units of temperature can have an offset");
+        $crate::impl_full_unit!($name);
+    };
+    (temperature, $name:ident) => {
+        $crate::impl_basic_ops!($name);
+        $crate::impl_rem!($name);
+        $crate::impl_partial_ord!($name);
+        $crate::impl_math!($name);
+        $crate::impl_approx_eq!($name);
+    };
+    (force, $name:ident) => {
+        $crate::__define_unit_ops!(@full $name);
+        $crate::impl_from_composite!($name);
+    };
+    (energy, $name:ident) => {
+        $crate::__define_unit_ops!(@full $name);
+        $crate::impl_from_composite!($name);
+    };
+    (power, $name:ident) => {
+        $crate::__define_unit_ops!(@full $name);
+        $crate::impl_from_composite!($name);
+    };
+    (pressure, $name:ident) => {
+        $crate::__define_unit_ops!(@full $name);
+        $crate::impl_from_composite!($name);
+    };
+    ($dimension:ident, $name:ident) => {
+        $crate::__define_unit_ops!(@full $name);
+    };
+}
+
+//converts the new unit to and from each built-in unit of its own dimension. units of user-defined dimensions convert
+//to and from their reference unit
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __define_unit_dimension {
+    (length, $name:ident, $reference:ty) => {
+        $crate::__length_units!(impl_from_quantity!($name));
+    };
+    (mass, $name:ident, $reference:ty) => {
+        $crate::__mass_units!(impl_from_quantity!($name));
+    };
+    (time, $name:ident, $reference:ty) => {
+        $crate::__time_units!(impl_from_quantity!($name));
+    };
+    (temperature, $name:ident, $reference:ty) => {
+        $crate::__temperature_units!(impl_from_quantity!($name));
+    };
+    (angle, $name:ident, $reference:ty) => {
+        $crate::__angle_units!(impl_from_quantity!($name));
+        $crate::impl_angle!($name);
+    };
+    (solid_angle, $name:ident, $reference:ty) => {
+        $crate::__solid_angle_units!(impl_from_quantity!($name));
+    };
+    (force, $name:ident, $reference:ty) => {
+        $crate::__force_units!(impl_from_quantity!($name));
+    };
+    (energy, $name:ident, $reference:ty) => {
+        $crate::__energy_units!(impl_from_quantity!($name));
+    };
+    (power, $name:ident, $reference:ty) => {
+        $crate::__power_units!(impl_from_quantity!($name));
+    };
+    (pressure, $name:ident, $reference:ty) => {
+        $crate::__pressure_units!(impl_from_quantity!($name));
+    };
+    ($dimension:ident, $name:ident, $reference:ty) => {
+        $crate::impl_from_quantity!($name, $reference);
+    };
+}
+
+//the lists below invoke the given macro once for every built-in unit of a dimension, and are empty for dimensions
+//whose feature is disabled
+
+#[cfg(feature = "length")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __length_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::length::metric::Kilometer);
+        $crate::$mac!($($args)*, $crate::length::metric::Meter);
+        $crate::$mac!($($args)*, $crate::length::metric::Centimeter);
+        $crate::$mac!($($args)*, $crate::length::metric::Millimeter);
+        $crate::$mac!($($args)*, $crate::length::metric::Micrometer);
+        $crate::$mac!($($args)*, $crate::length::metric::Nanometer);
+        $crate::$mac!($($args)*, $crate::length::metric::Picometer);
+        $crate::$mac!($($args)*, $crate::length::metric::Femtometer);
+        $crate::$mac!($($args)*, $crate::length::imperial::Inch);
+        $crate::$mac!($($args)*, $crate::length::imperial::Foot);
+        $crate::$mac!($($args)*, $crate::length::imperial::Yard);
+        $crate::$mac!($($args)*, $crate::length::imperial::Mile);
+        $crate::$mac!($($args)*, $crate::length::astronomical::AU);
+        $crate::$mac!($($args)*, $crate::length::astronomical::Lightyear);
+    }
+}
+
+#[cfg(not(feature = "length"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __length_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(feature = "mass")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __mass_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::mass::metric::Ton);
+        $crate::$mac!($($args)*, $crate::mass::metric::Kilogram);
+        $crate::$mac!($($args)*, $crate::mass::metric::Gram);
+        $crate::$mac!($($args)*, $crate::mass::metric::Centigram);
+        $crate::$mac!($($args)*, $crate::mass::metric::Milligram);
+        $crate::$mac!($($args)*, $crate::mass::imperial::Ton);
+        $crate::$mac!($($args)*, $crate::mass::imperial::Pound);
+        $crate::$mac!($($args)*, $crate::mass::imperial::Ounce);
+    }
+}
+
+#[cfg(not(feature = "mass"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __mass_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(feature = "time")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __time_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::time::Second);
+        $crate::$mac!($($args)*, $crate::time::Minute);
+        $crate::$mac!($($args)*, $crate::time::Hour);
+        $crate::$mac!($($args)*, $crate::time::Day);
+        $crate::$mac!($($args)*, $crate::time::Year);
+        $crate::$mac!($($args)*, $crate::time::Decade);
+        $crate::$mac!($($args)*, $crate::time::Century);
+        $crate::$mac!($($args)*, $crate::time::Millennium);
+    }
+}
+
+#[cfg(not(feature = "time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __time_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(feature = "temperature")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __temperature_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::temperature::Celsius);
+        $crate::$mac!($($args)*, $crate::temperature::Fahrenheit);
+        $crate::$mac!($($args)*, $crate::temperature::Kelvin);
+    }
+}
+
+#[cfg(not(feature = "temperature"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __temperature_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(feature = "angle")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __angle_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::angle::Radian);
+        $crate::$mac!($($args)*, $crate::angle::Degree);
+        $crate::$mac!($($args)*, $crate::angle::Gradian);
+        $crate::$mac!($($args)*, $crate::angle::Turn);
+        $crate::$mac!($($args)*, $crate::angle::Arcminute);
+        $crate::$mac!($($args)*, $crate::angle::Arcsecond);
+    }
+}
+
+#[cfg(not(feature = "angle"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __angle_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(feature = "solid_angle")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __solid_angle_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::solid_angle::Steradian);
+        $crate::$mac!($($args)*, $crate::solid_angle::SquareDegree);
+    }
+}
+
+#[cfg(not(feature = "solid_angle"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __solid_angle_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __force_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::force::Newton);
+        $crate::$mac!($($args)*, $crate::force::Dyne);
+        $crate::$mac!($($args)*, $crate::force::PoundForce);
+        $crate::$mac!($($args)*, $crate::force::KilogramForce);
+        $crate::$mac!($($args)*, $crate::force::Poundal);
+    }
+}
+
+#[cfg(not(all(feature = "length", feature = "mass", feature = "time")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __force_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __energy_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::energy::Joule);
+        $crate::$mac!($($args)*, $crate::energy::Calorie);
+        $crate::$mac!($($args)*, $crate::energy::Kilocalorie);
+        $crate::$mac!($($args)*, $crate::energy::WattHour);
+        $crate::$mac!($($args)*, $crate::energy::KilowattHour);
+        $crate::$mac!($($args)*, $crate::energy::BritishThermalUnit);
+        $crate::$mac!($($args)*, $crate::energy::Electronvolt);
+        $crate::$mac!($($args)*, $crate::energy::Erg);
+        $crate::$mac!($($args)*, $crate::energy::FootPound);
+    }
+}
+
+#[cfg(not(all(feature = "length", feature = "mass", feature = "time")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __energy_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __power_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::power::Watt);
+        $crate::$mac!($($args)*, $crate::power::MechanicalHorsepower);
+        $crate::$mac!($($args)*, $crate::power::MetricHorsepower);
+        $crate::$mac!($($args)*, $crate::power::BtuPerHour);
+        $crate::$mac!($($args)*, $crate::power::TonOfRefrigeration);
+    }
+}
+
+#[cfg(not(all(feature = "length", feature = "mass", feature = "time")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __power_units {
+    ($($args:tt)*) => {}
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __pressure_units {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, $crate::pressure::Pascal);
+        $crate::$mac!($($args)*, $crate::pressure::Bar);
+        $crate::$mac!($($args)*, $crate::pressure::Millibar);
+        $crate::$mac!($($args)*, $crate::pressure::Atmosphere);
+        $crate::$mac!($($args)*, $crate::pressure::Psi);
+        $crate::$mac!($($args)*, $crate::pressure::Torr);
+        $crate::$mac!($($args)*, $crate::pressure::MillimeterOfMercury);
+        $crate::$mac!($($args)*, $crate::pressure::InchOfWater);
+    }
+}
+
+#[cfg(not(all(feature = "length", feature = "mass", feature = "time")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __pressure_units {
+    ($($args:tt)*) => {}
+}
+
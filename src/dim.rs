@@ -0,0 +1,389 @@
+//! Type-level dimension vectors, which decide whether two units measure the same thing.
+//!
+//! The dimension of a base unit is an `Exponents` vector, holding the exponent of each built-in base dimension and a
+//! list of terms for the dimensions declared with `define_dimension!`. Multiplying units adds their vectors and
+//! dividing them subtracts, so `Div<Meter, Second>` and `Mul<Meter, Per<Second>>` end up with the same vector. The
+//! declared dimensions are identified by 64 bits hashed from their path, since they cannot have a slot of their own.
+
+use core::marker::PhantomData;
+
+use composite::{Different, Same};
+
+/// A base unit, or a composite of base units, along with its dimension.
+pub trait Dimensioned {
+    /// The dimension, as `Exponents`.
+    type Dim;
+}
+
+/// The exponents of length, mass, time, temperature, current, angle and solid angle, and the `Cons` list of `Term`s
+/// of declared dimensions.
+pub struct Exponents<L, M, T, K, I, A, S, X>(PhantomData<(L, M, T, K)>, PhantomData<(I, A, S, X)>);
+
+type One = Inc<Zero>;
+
+/// The dimension of a plain number.
+pub type Dimensionless = Exponents<Zero, Zero, Zero, Zero, Zero, Zero, Zero, Nil>;
+/// The dimension of `Meter`.
+pub type Length = Exponents<One, Zero, Zero, Zero, Zero, Zero, Zero, Nil>;
+/// The dimension of `Kilogram`.
+pub type Mass = Exponents<Zero, One, Zero, Zero, Zero, Zero, Zero, Nil>;
+/// The dimension of `Second`.
+pub type Time = Exponents<Zero, Zero, One, Zero, Zero, Zero, Zero, Nil>;
+/// The dimension of `Kelvin`.
+pub type Temperature = Exponents<Zero, Zero, Zero, One, Zero, Zero, Zero, Nil>;
+/// The dimension of `Ampere`.
+pub type Current = Exponents<Zero, Zero, Zero, Zero, One, Zero, Zero, Nil>;
+/// The dimension of `Radian`.
+pub type Angle = Exponents<Zero, Zero, Zero, Zero, Zero, One, Zero, Nil>;
+/// The dimension of `Steradian`.
+pub type SolidAngle = Exponents<Zero, Zero, Zero, Zero, Zero, Zero, One, Nil>;
+/// The dimension declared with the identifier `I`.
+pub type Declared<I> = Exponents<Zero, Zero, Zero, Zero, Zero, Zero, Zero, Cons<Term<I, One>, Nil>>;
+
+/// One bit of the identifier of a declared dimension.
+pub struct Bit<const B: bool>;
+
+/// Two halves of the identifier of a declared dimension.
+pub struct Node<L, R>(PhantomData<(L, R)>);
+
+/// The exponent zero.
+pub struct Zero;
+
+/// One more than the exponent `N`.
+pub struct Inc<N>(PhantomData<N>);
+
+/// One less than the exponent `N`.
+pub struct Dec<N>(PhantomData<N>);
+
+/// The declared dimension `I` raised to the exponent `E`.
+pub struct Term<I, E>(PhantomData<(I, E)>);
+
+/// The empty list of terms.
+pub struct Nil;
+
+/// A list of terms, starting with `H`.
+pub struct Cons<H, T>(PhantomData<(H, T)>);
+
+/// Hashes the path of a declared dimension, with 64-bit FNV-1a.
+pub const fn hash(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Returns bit `k` of the hash of `name`.
+pub const fn bit(name: &str, k: u32) -> bool {
+    (hash(name) >> k) & 1 == 1
+}
+
+//expands to the identifier of the declared dimension with the given path, as a tree of the 64 bits of its hash
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __dimension_id {
+    ($name:expr) => {
+        $crate::dim::Node<
+            $crate::dim::Node<
+                $crate::dim::Node<$crate::__dimension_id!(@byte $name, 0), $crate::__dimension_id!(@byte $name, 8)>,
+                $crate::dim::Node<$crate::__dimension_id!(@byte $name, 16), $crate::__dimension_id!(@byte $name, 24)>
+            >,
+            $crate::dim::Node<
+                $crate::dim::Node<$crate::__dimension_id!(@byte $name, 32), $crate::__dimension_id!(@byte $name, 40)>,
+                $crate::dim::Node<$crate::__dimension_id!(@byte $name, 48), $crate::__dimension_id!(@byte $name, 56)>
+            >
+        >
+    };
+    (@byte $name:expr, $k:expr) => {
+        $crate::dim::Node<
+            $crate::dim::Node<
+                $crate::dim::Node<$crate::__dimension_id!(@bit $name, $k), $crate::__dimension_id!(@bit $name, $k + 1)>,
+                $crate::dim::Node<$crate::__dimension_id!(@bit $name, $k + 2), $crate::__dimension_id!(@bit $name, $k + 3)>
+            >,
+            $crate::dim::Node<
+                $crate::dim::Node<$crate::__dimension_id!(@bit $name, $k + 4), $crate::__dimension_id!(@bit $name, $k + 5)>,
+                $crate::dim::Node<$crate::__dimension_id!(@bit $name, $k + 6), $crate::__dimension_id!(@bit $name, $k + 7)>
+            >
+        >
+    };
+    (@bit $name:expr, $k:expr) => {
+        $crate::dim::Bit<{ $crate::dim::bit($name, $k) }>
+    };
+}
+
+/// Type-level `&&`, on `Same` as true and `Different` as false.
+pub trait And<Rhs> {
+    type Output;
+}
+
+impl And<Same> for Same {
+    type Output = Same;
+}
+
+impl And<Different> for Same {
+    type Output = Different;
+}
+
+impl<Rhs> And<Rhs> for Different {
+    type Output = Different;
+}
+
+/// Type-level `if`, on `Same` as true and `Different` as false.
+pub trait Select<Then, Else> {
+    type Output;
+}
+
+impl<Then, Else> Select<Then, Else> for Same {
+    type Output = Then;
+}
+
+impl<Then, Else> Select<Then, Else> for Different {
+    type Output = Else;
+}
+
+/// Compares two identifiers of declared dimensions, resolving to `Same` or `Different`.
+pub trait IdEq<Rhs> {
+    type Output;
+}
+
+impl IdEq<Bit<true>> for Bit<true> {
+    type Output = Same;
+}
+
+impl IdEq<Bit<false>> for Bit<false> {
+    type Output = Same;
+}
+
+impl IdEq<Bit<false>> for Bit<true> {
+    type Output = Different;
+}
+
+impl IdEq<Bit<true>> for Bit<false> {
+    type Output = Different;
+}
+
+impl<L1, R1, L2, R2> IdEq<Node<L2, R2>> for Node<L1, R1>
+    where L1: IdEq<L2>,
+          R1: IdEq<R2>,
+          L1::Output: And<R1::Output>
+{
+    type Output = <L1::Output as And<R1::Output>>::Output;
+}
+
+/// Adds two exponents, or two dimensions.
+pub trait Plus<Rhs> {
+    type Output;
+}
+
+impl<Rhs> Plus<Rhs> for Zero {
+    type Output = Rhs;
+}
+
+impl<N> Plus<Zero> for Inc<N> {
+    type Output = Inc<N>;
+}
+
+impl<N, M> Plus<Inc<M>> for Inc<N>
+    where N: Plus<M>
+{
+    type Output = Inc<Inc<N::Output>>;
+}
+
+impl<N, M> Plus<Dec<M>> for Inc<N>
+    where N: Plus<M>
+{
+    type Output = N::Output;
+}
+
+impl<N> Plus<Zero> for Dec<N> {
+    type Output = Dec<N>;
+}
+
+impl<N, M> Plus<Dec<M>> for Dec<N>
+    where N: Plus<M>
+{
+    type Output = Dec<Dec<N::Output>>;
+}
+
+impl<N, M> Plus<Inc<M>> for Dec<N>
+    where N: Plus<M>
+{
+    type Output = N::Output;
+}
+
+/// Negates an exponent, or every exponent of a list of terms or a dimension.
+pub trait Negate {
+    type Output;
+}
+
+impl Negate for Zero {
+    type Output = Zero;
+}
+
+impl<N> Negate for Inc<N>
+    where N: Negate
+{
+    type Output = Dec<N::Output>;
+}
+
+impl<N> Negate for Dec<N>
+    where N: Negate
+{
+    type Output = Inc<N::Output>;
+}
+
+impl Negate for Nil {
+    type Output = Nil;
+}
+
+impl<I, E, T> Negate for Cons<Term<I, E>, T>
+    where E: Negate,
+          T: Negate
+{
+    type Output = Cons<Term<I, E::Output>, T::Output>;
+}
+
+/// Tells whether an exponent is zero, resolving to `Same` or `Different`.
+pub trait IsZero {
+    type Output;
+}
+
+impl IsZero for Zero {
+    type Output = Same;
+}
+
+impl<N> IsZero for Inc<N> {
+    type Output = Different;
+}
+
+impl<N> IsZero for Dec<N> {
+    type Output = Different;
+}
+
+/// Joins two lists of terms.
+pub trait Concat<Rhs> {
+    type Output;
+}
+
+impl<Rhs> Concat<Rhs> for Nil {
+    type Output = Rhs;
+}
+
+impl<H, T, Rhs> Concat<Rhs> for Cons<H, T>
+    where T: Concat<Rhs>
+{
+    type Output = Cons<H, T::Output>;
+}
+
+/// Sums the exponents of the declared dimension `I` in a list of terms.
+pub trait ExponentOf<I> {
+    type Output;
+}
+
+impl<I> ExponentOf<I> for Nil {
+    type Output = Zero;
+}
+
+impl<I, J, E, T> ExponentOf<I> for Cons<Term<J, E>, T>
+    where J: IdEq<I>,
+          J::Output: Select<E, Zero>,
+          T: ExponentOf<I>,
+          <J::Output as Select<E, Zero>>::Output: Plus<T::Output>
+{
+    type Output = <<J::Output as Select<E, Zero>>::Output as Plus<T::Output>>::Output;
+}
+
+/// Removes the terms of the declared dimension `I` from a list of terms.
+pub trait Without<I> {
+    type Output;
+}
+
+impl<I> Without<I> for Nil {
+    type Output = Nil;
+}
+
+impl<I, J, E, T> Without<I> for Cons<Term<J, E>, T>
+    where J: IdEq<I>,
+          T: Without<I>,
+          J::Output: Select<T::Output, Cons<Term<J, E>, T::Output>>
+{
+    type Output = <J::Output as Select<T::Output, Cons<Term<J, E>, T::Output>>>::Output;
+}
+
+/// Tells whether the exponent of every base dimension in a dimension, or every declared dimension in a list of terms,
+/// sums to zero, resolving to `Same` or `Different`.
+pub trait IsDimensionless {
+    type Output;
+}
+
+impl IsDimensionless for Nil {
+    type Output = Same;
+}
+
+impl<I, E, T> IsDimensionless for Cons<Term<I, E>, T>
+    where Cons<Term<I, E>, T>: ExponentOf<I> + Without<I>,
+          <Cons<Term<I, E>, T> as ExponentOf<I>>::Output: IsZero,
+          <Cons<Term<I, E>, T> as Without<I>>::Output: IsDimensionless,
+          <<Cons<Term<I, E>, T> as ExponentOf<I>>::Output as IsZero>::Output:
+              And<<<Cons<Term<I, E>, T> as Without<I>>::Output as IsDimensionless>::Output>
+{
+    type Output = <<<Cons<Term<I, E>, T> as ExponentOf<I>>::Output as IsZero>::Output as
+                   And<<<Cons<Term<I, E>, T> as Without<I>>::Output as IsDimensionless>::Output>>::Output;
+}
+
+impl<L1, M1, T1, K1, I1, A1, S1, X1, L2, M2, T2, K2, I2, A2, S2, X2> Plus<Exponents<L2, M2, T2, K2, I2, A2, S2, X2>>
+    for Exponents<L1, M1, T1, K1, I1, A1, S1, X1>
+    where L1: Plus<L2>,
+          M1: Plus<M2>,
+          T1: Plus<T2>,
+          K1: Plus<K2>,
+          I1: Plus<I2>,
+          A1: Plus<A2>,
+          S1: Plus<S2>,
+          X1: Concat<X2>
+{
+    type Output = Exponents<L1::Output, M1::Output, T1::Output, K1::Output, I1::Output, A1::Output, S1::Output,
+                            X1::Output>;
+}
+
+impl<L, M, T, K, I, A, S, X> Negate for Exponents<L, M, T, K, I, A, S, X>
+    where L: Negate,
+          M: Negate,
+          T: Negate,
+          K: Negate,
+          I: Negate,
+          A: Negate,
+          S: Negate,
+          X: Negate
+{
+    type Output = Exponents<L::Output, M::Output, T::Output, K::Output, I::Output, A::Output, S::Output, X::Output>;
+}
+
+impl<L, M, T, K, I, A, S, X> IsDimensionless for Exponents<L, M, T, K, I, A, S, X>
+    where L: IsZero,
+          M: IsZero,
+          T: IsZero,
+          K: IsZero,
+          I: IsZero,
+          A: IsZero,
+          S: IsZero,
+          X: IsDimensionless,
+          L::Output: And<M::Output>,
+          <L::Output as And<M::Output>>::Output: And<T::Output>,
+          <<L::Output as And<M::Output>>::Output as And<T::Output>>::Output: And<K::Output>,
+          <<<L::Output as And<M::Output>>::Output as And<T::Output>>::Output as And<K::Output>>::Output: And<I::Output>,
+          <<<<L::Output as And<M::Output>>::Output as And<T::Output>>::Output as And<K::Output>>::Output as
+              And<I::Output>>::Output: And<A::Output>,
+          <<<<<L::Output as And<M::Output>>::Output as And<T::Output>>::Output as And<K::Output>>::Output as
+              And<I::Output>>::Output as And<A::Output>>::Output: And<S::Output>,
+          <<<<<<L::Output as And<M::Output>>::Output as And<T::Output>>::Output as And<K::Output>>::Output as
+              And<I::Output>>::Output as And<A::Output>>::Output as And<S::Output>>::Output: And<X::Output>
+{
+    type Output = <<<<<<<L::Output as And<M::Output>>::Output as And<T::Output>>::Output as And<K::Output>>::Output as
+                   And<I::Output>>::Output as And<A::Output>>::Output as And<S::Output>>::Output as
+                   And<X::Output>>::Output;
+}
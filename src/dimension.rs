@@ -0,0 +1,155 @@
+//! Defines traits for each physical dimension, so functions can accept any unit of the right kind.
+//!
+//! Each trait is implemented for every unit and composite of its dimension, however the composite is written, so
+//! `Mul<Meter, Per<Second>>` is a `Speed` just like `Div<Kilometer, Hour>`. Its `to_si` method converts any of them
+//! into the coherent SI unit of the dimension.
+//!
+//! ```
+//! use metric::composite::{Div, Mul, Per, Unit};
+//! use metric::dimension::{Acceleration, Force, Length, Speed};
+//! use metric::length::metric::{Kilometer, Meter};
+//! use metric::mass::metric::Kilogram;
+//! use metric::time::{Hour, Second};
+//!
+//! fn travel_time<L: Length, V: Speed>(distance: L, speed: V) -> Second {
+//!     Second(distance.to_si().0 / speed.to_si().inner())
+//! }
+//!
+//! fn is_acceleration<A: Acceleration>(_: A) {}
+//! fn is_force<F: Force>(_: F) {}
+//!
+//! assert_eq!(travel_time(Kilometer::new(36.0), Kilometer(36.0) / Hour(1.0)), Second(3600.0));
+//! assert_eq!(travel_time(Meter(10.0), Mul::<Meter, Per<Second>>::from_value(2.0)), Second(5.0));
+//! is_acceleration(Meter(1.0) / Second(1.0) / Second(1.0));
+//! is_acceleration(Div::<Div<Meter, Second>, Second>::from_value(9.8));
+//! is_force(Kilogram(2.0) * (Meter(9.8) / (Second(1.0) * Second(1.0))));
+//! ```
+
+#[cfg(any(all(feature = "length", feature = "time"), all(feature = "angle", feature = "time")))]
+use composite::Div;
+#[cfg(feature = "length")]
+use composite::Mul;
+#[cfg(any(feature = "length", feature = "mass", feature = "time", feature = "temperature", feature = "angle",
+          feature = "solid_angle", feature = "electrical"))]
+use composite::{convert_raw, from_raw_value, Compare, Quantity, Same};
+
+#[cfg(feature = "length")]
+use length::metric::Meter;
+#[cfg(feature = "mass")]
+use mass::metric::Kilogram;
+#[cfg(feature = "temperature")]
+use temperature::Kelvin;
+#[cfg(feature = "time")]
+use time::Second;
+#[cfg(feature = "angle")]
+use angle::Radian;
+#[cfg(feature = "solid_angle")]
+use solid_angle::Steradian;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use force::Newton;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use energy::Joule;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use power::Watt;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use pressure::Pascal;
+#[cfg(feature = "electrical")]
+use electrical::{Ampere, Coulomb, Farad, Henry, Ohm, Siemens, Volt};
+
+macro_rules! dimension {
+    ($(#[$attr:meta])* $name:ident => $base:ty) => {
+        $(#[$attr])*
+        pub trait $name: Quantity {
+            /// Converts `self` into the coherent SI unit of this dimension, whichever unit or composite it is in.
+            fn to_si(self) -> $base;
+        }
+
+        impl<T> $name for T
+            where T: Quantity,
+                  T::Base: Compare<<$base as Quantity>::Base, Result = Same>
+        {
+            #[inline(always)]
+            fn to_si(self) -> $base {
+                from_raw_value(convert_raw::<T, $base>(self.inner()))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "length")]
+dimension!(/// Implemented by every unit of length, e.g. `Meter`, `Foot` or `AU`.
+           Length => Meter);
+#[cfg(feature = "mass")]
+dimension!(/// Implemented by every unit of mass, e.g. `Kilogram` or `Pound`.
+           Mass => Kilogram);
+#[cfg(feature = "time")]
+dimension!(/// Implemented by every unit of time, e.g. `Second` or `Hour`.
+           Time => Second);
+#[cfg(feature = "temperature")]
+dimension!(/// Implemented by every temperature scale, e.g. `Kelvin` or `Celsius`.
+           Temperature => Kelvin);
+#[cfg(feature = "length")]
+dimension!(/// Implemented by every unit of area, e.g. `Mul<Foot, Foot>`.
+           Area => Mul<Meter, Meter>);
+#[cfg(feature = "length")]
+dimension!(/// Implemented by every unit of volume, e.g. `Mul<Centimeter, Mul<Centimeter, Centimeter>>`.
+           Volume => Mul<Meter, Mul<Meter, Meter>>);
+#[cfg(all(feature = "length", feature = "time"))]
+dimension!(/// Implemented by every unit of speed, e.g. `Div<Kilometer, Hour>`.
+           Speed => Div<Meter, Second>);
+#[cfg(all(feature = "length", feature = "time"))]
+dimension!(/// Implemented by every unit of acceleration, e.g. `Div<Foot, Mul<Second, Second>>`.
+           Acceleration => Div<Meter, Mul<Second, Second>>);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+dimension!(/// Implemented by every unit of force, e.g. `Newton` or `PoundForce`.
+           ///
+           /// Composites such as `Mul<Gram, Div<Centimeter, Mul<Second, Second>>>` implement it too, and convert
+           /// into these with `From`.
+           Force => Newton);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+dimension!(/// Implemented by every unit of energy, e.g. `Joule` or `KilowattHour`.
+           ///
+           /// Composites such as `Mul<PoundForce, Foot>` implement it too, and convert into these with `From`.
+           Energy => Joule);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+dimension!(/// Implemented by every unit of power, e.g. `Watt` or `MechanicalHorsepower`.
+           ///
+           /// Composites such as `Div<BritishThermalUnit, Hour>` implement it too, and convert into these with
+           /// `From`.
+           Power => Watt);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+dimension!(/// Implemented by every unit of pressure, e.g. `Pascal`, `Psi` or `BarGauge`.
+           ///
+           /// Composites such as `Div<PoundForce, Mul<Inch, Inch>>` implement it too, and convert into these with
+           /// `From`.
+           Pressure => Pascal);
+#[cfg(feature = "angle")]
+dimension!(/// Implemented by every unit of plane angle, e.g. `Degree` or `Turn`.
+           Angle => Radian);
+#[cfg(all(feature = "angle", feature = "time"))]
+dimension!(/// Implemented by every unit of angular velocity, e.g. `Div<Turn, Minute>`.
+           AngularVelocity => Div<Radian, Second>);
+#[cfg(feature = "solid_angle")]
+dimension!(/// Implemented by every unit of solid angle, e.g. `Steradian` or `SquareDegree`.
+           SolidAngle => Steradian);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of electric current, e.g. `Ampere` or `Milliampere`.
+           Current => Ampere);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of voltage, e.g. `Volt`.
+           Voltage => Volt);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of electrical resistance, e.g. `Ohm` or `Kiloohm`.
+           Resistance => Ohm);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of electrical conductance, e.g. `Siemens`.
+           Conductance => Siemens);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of electric charge, e.g. `Coulomb`.
+           Charge => Coulomb);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of capacitance, e.g. `Farad` or `Microfarad`.
+           Capacitance => Farad);
+#[cfg(feature = "electrical")]
+dimension!(/// Implemented by every unit of inductance, e.g. `Henry` or `Nanohenry`.
+           Inductance => Henry);
@@ -1 +1,128 @@
+//! Electrical units.
+//!
+//! Each unit converts from any composite of its dimension, so Ohm's law and friends stay typed whichever way round
+//! they are written: a `Volt` divided by an `Ampere` converts into an `Ohm` and an `Ampere` times an `Ohm` back into a
+//! `Volt`, either order of `Volt` and `Ampere` gives a `Watt`, and a `Coulomb` divided by a `Second` gives an `Ampere`.
+//! The composites may be in any units of the right dimensions, such as milliamperes. `Watt` is re-exported from
+//! `power`.
+//!
+//! ```
+//! use metric::electrical::{Ampere, Coulomb, Kiloohm, Milliampere, Ohm, Volt, Watt};
+//! use metric::energy::Joule;
+//! use metric::time::{Hour, Second};
+//!
+//! let resistance: Ohm = (Volt(12.0) / Ampere(0.5)).into();
+//! assert_eq!(resistance, Ohm(24.0));
+//! assert_eq!(Watt::from(Volt(12.0) * Ampere(0.5)), Watt(6.0));
+//! assert_eq!(Ampere::from(Volt(5.0) / Kiloohm::new(1.0)), Milliampere::new(5.0));
+//! assert_eq!(Coulomb::from(Ampere(2.0) * Hour(1.0)), Coulomb(7200.0));
+//!
+//! assert_eq!(Volt::from(Ampere(0.5) * Ohm(24.0)), Volt(12.0));
+//! assert_eq!(Watt::from(Ampere(0.5) * Volt(12.0)), Watt(6.0));
+//! assert_eq!(Coulomb::from(Second(3.0) * Ampere(2.0)), Coulomb(6.0));
+//! assert_eq!(Ampere::from(Coulomb(6.0) / Second(3.0)), Ampere(2.0));
+//! assert_eq!(Joule::from(Volt(12.0) * Coulomb(0.5)), Joule(6.0));
+//! ```
 
+use prefix::{Kilo, Micro, Milli, Nano, Prefixed};
+
+pub use power::Watt;
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Ampere(pub f64);
+pub type Amperes = Ampere;
+
+impl_full_unit!(Ampere);
+impl_unit_debug!(Ampere => "A");
+impl_quantity!(Ampere => Ampere, 1.0);
+impl_composite_base!(Ampere);
+impl_from_composite!(Ampere);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Volt(pub f64);
+pub type Volts = Volt;
+
+impl_full_unit!(Volt);
+impl_unit_debug!(Volt => "V");
+impl_quantity!(Volt => Volt, 1.0);
+impl_composite_base!(Volt);
+impl_from_composite!(Volt);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Ohm(pub f64);
+pub type Ohms = Ohm;
+
+impl_full_unit!(Ohm);
+impl_unit_debug!(Ohm => "Ω");
+impl_quantity!(Ohm => Ohm, 1.0);
+impl_composite_base!(Ohm);
+impl_from_composite!(Ohm);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Siemens(pub f64);
+
+impl_full_unit!(Siemens);
+impl_unit_debug!(Siemens => "S");
+impl_quantity!(Siemens => Siemens, 1.0);
+impl_composite_base!(Siemens);
+impl_from_composite!(Siemens);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Coulomb(pub f64);
+pub type Coulombs = Coulomb;
+
+impl_full_unit!(Coulomb);
+impl_unit_debug!(Coulomb => "C");
+impl_quantity!(Coulomb => Coulomb, 1.0);
+impl_composite_base!(Coulomb);
+impl_from_composite!(Coulomb);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Farad(pub f64);
+pub type Farads = Farad;
+
+impl_full_unit!(Farad);
+impl_unit_debug!(Farad => "F");
+impl_quantity!(Farad => Farad, 1.0);
+impl_composite_base!(Farad);
+impl_from_composite!(Farad);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Henry(pub f64);
+pub type Henries = Henry;
+
+impl_full_unit!(Henry);
+impl_unit_debug!(Henry => "H");
+impl_quantity!(Henry => Henry, 1.0);
+impl_composite_base!(Henry);
+impl_from_composite!(Henry);
+
+/// A thousandth of an ampere, as a prefixed `Ampere`.
+pub type Milliampere = Prefixed<Milli, Ampere>;
+pub type Milliamperes = Milliampere;
+
+/// A thousand ohms, as a prefixed `Ohm`.
+pub type Kiloohm = Prefixed<Kilo, Ohm>;
+pub type Kiloohms = Kiloohm;
+
+/// A millionth of a farad, as a prefixed `Farad`.
+pub type Microfarad = Prefixed<Micro, Farad>;
+pub type Microfarads = Microfarad;
+
+/// A billionth of a henry, as a prefixed `Henry`.
+pub type Nanohenry = Prefixed<Nano, Henry>;
+pub type Nanohenries = Nanohenry;
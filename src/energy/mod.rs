@@ -0,0 +1,180 @@
+//! Units of energy.
+//!
+//! Every unit of energy converts to and from any composite with the dimension of an energy, such as the
+//! `Mul<Newton, Meter>` produced by multiplying a force by a distance, or a mass times a squared velocity spelled out
+//! in base units.
+//!
+//! ```
+//! use metric::composite::Mul;
+//! use metric::energy::{Calorie, Joule, Kilojoule, KilowattHour};
+//! use metric::force::Newton;
+//! use metric::length::metric::Meter;
+//! use metric::mass::metric::Kilogram;
+//! use metric::time::Second;
+//!
+//! let work: Joule = (Newton(250.0) * Meter(4.0)).into();
+//! assert_eq!(work, Joule(1000.0));
+//! assert_eq!(Kilojoule::from(work), Kilojoule::new(1.0));
+//! assert_eq!(Joule::from(Calorie(1.0)), Joule(4.184));
+//! assert_eq!(Joule::from(Kilogram(2.0) * Meter(3.0) * Meter(5.0) / (Second(1.0) * Second(1.0))), Joule(30.0));
+//! let composite: Mul<Newton, Meter> = KilowattHour(1.0).into();
+//! assert_eq!(composite.0, Newton(3_600_000.0));
+//! ```
+
+use prefix::{Kilo, Prefixed};
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Joule(pub f64);
+pub type Joules = Joule;
+
+impl_full_unit!(Joule);
+impl_unit_debug!(Joule => "J");
+impl_quantity!(Joule => Joule, 1.0);
+impl_composite_base!(Joule);
+impl_from_composite!(Joule);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Calorie(pub f64);
+pub type Calories = Calorie;
+
+impl_full_unit!(Calorie);
+impl_unit_debug!(Calorie => "cal");
+impl_quantity!(Calorie => Joule, 4.184);
+impl_composite_base!(Calorie);
+impl_from_composite!(Calorie);
+
+impl_from_quantity!(Calorie, Joule);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Kilocalorie(pub f64);
+pub type Kilocalories = Kilocalorie;
+
+impl_full_unit!(Kilocalorie);
+impl_unit_debug!(Kilocalorie => "kcal");
+impl_quantity!(Kilocalorie => Joule, 4_184.0);
+impl_composite_base!(Kilocalorie);
+impl_from_composite!(Kilocalorie);
+
+impl_from_quantity!(Kilocalorie, Joule);
+impl_from_quantity!(Kilocalorie, Calorie);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct WattHour(pub f64);
+pub type WattHours = WattHour;
+
+impl_full_unit!(WattHour);
+impl_unit_debug!(WattHour => "Wh");
+impl_quantity!(WattHour => Joule, 3_600.0);
+impl_composite_base!(WattHour);
+impl_from_composite!(WattHour);
+
+impl_from_quantity!(WattHour, Joule);
+impl_from_quantity!(WattHour, Calorie);
+impl_from_quantity!(Kilocalorie, WattHour);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct KilowattHour(pub f64);
+pub type KilowattHours = KilowattHour;
+
+impl_full_unit!(KilowattHour);
+impl_unit_debug!(KilowattHour => "kWh");
+impl_quantity!(KilowattHour => Joule, 3_600_000.0);
+impl_composite_base!(KilowattHour);
+impl_from_composite!(KilowattHour);
+
+impl_from_quantity!(KilowattHour, Joule);
+impl_from_quantity!(KilowattHour, Calorie);
+impl_from_quantity!(KilowattHour, Kilocalorie);
+impl_from_quantity!(KilowattHour, WattHour);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct BritishThermalUnit(pub f64);
+pub type BritishThermalUnits = BritishThermalUnit;
+
+impl_full_unit!(BritishThermalUnit);
+impl_unit_debug!(BritishThermalUnit => "BTU");
+impl_quantity!(BritishThermalUnit => Joule, 1_055.05585262);
+impl_composite_base!(BritishThermalUnit);
+impl_from_composite!(BritishThermalUnit);
+
+impl_from_quantity!(BritishThermalUnit, Joule);
+impl_from_quantity!(BritishThermalUnit, Calorie);
+impl_from_quantity!(Kilocalorie, BritishThermalUnit);
+impl_from_quantity!(WattHour, BritishThermalUnit);
+impl_from_quantity!(KilowattHour, BritishThermalUnit);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Electronvolt(pub f64);
+pub type Electronvolts = Electronvolt;
+
+impl_full_unit!(Electronvolt);
+impl_unit_debug!(Electronvolt => "eV");
+impl_quantity!(Electronvolt => Joule, 1.602176634e-19);
+impl_composite_base!(Electronvolt);
+impl_from_composite!(Electronvolt);
+
+impl_from_quantity!(Joule, Electronvolt);
+impl_from_quantity!(Calorie, Electronvolt);
+impl_from_quantity!(Kilocalorie, Electronvolt);
+impl_from_quantity!(WattHour, Electronvolt);
+impl_from_quantity!(KilowattHour, Electronvolt);
+impl_from_quantity!(BritishThermalUnit, Electronvolt);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Erg(pub f64);
+pub type Ergs = Erg;
+
+impl_full_unit!(Erg);
+impl_unit_debug!(Erg => "erg");
+impl_quantity!(Erg => Joule, 1e-7);
+impl_composite_base!(Erg);
+impl_from_composite!(Erg);
+
+impl_from_quantity!(Joule, Erg);
+impl_from_quantity!(Calorie, Erg);
+impl_from_quantity!(Kilocalorie, Erg);
+impl_from_quantity!(WattHour, Erg);
+impl_from_quantity!(KilowattHour, Erg);
+impl_from_quantity!(BritishThermalUnit, Erg);
+impl_from_quantity!(Erg, Electronvolt);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct FootPound(pub f64);
+pub type FootPounds = FootPound;
+
+impl_full_unit!(FootPound);
+impl_unit_debug!(FootPound => "ft·lbf");
+impl_quantity!(FootPound => Joule, 0.3048 * 4.4482216152605);
+impl_composite_base!(FootPound);
+impl_from_composite!(FootPound);
+
+impl_from_quantity!(FootPound, Joule);
+impl_from_quantity!(Calorie, FootPound);
+impl_from_quantity!(Kilocalorie, FootPound);
+impl_from_quantity!(WattHour, FootPound);
+impl_from_quantity!(KilowattHour, FootPound);
+impl_from_quantity!(BritishThermalUnit, FootPound);
+impl_from_quantity!(FootPound, Electronvolt);
+impl_from_quantity!(FootPound, Erg);
+
+/// A thousand joules, as a prefixed `Joule`.
+pub type Kilojoule = Prefixed<Kilo, Joule>;
+pub type Kilojoules = Kilojoule;
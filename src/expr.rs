@@ -0,0 +1,328 @@
+//! Provides `unit!` and `qty!`, for writing units and quantities as expressions of unit symbols.
+
+/// Expands a unit expression into the corresponding `composite` type.
+///
+/// Expressions are built from unit symbols such as `kg`, `m`, `s`, `km` or `hr`, or the names of unit types in scope,
+/// joined by `*` and `/`. Factors may be raised to an integer power between -9 and 9 with `^`,
+/// and parentheses group a sub-expression into a single factor. The units multiplied together are nested to the
+/// right, followed by a single `Div` over the units divided by, so `unit!(kg * m / s^2)` is
+/// `Mul<Kilogram, Div<Meter, Mul<Second, Second>>>`. An expression with nothing to divide, such as `unit!(s^-1)` or
+/// `unit!(1 / s)`, is a reciprocal, `Per<Second>`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::composite::{Div, Mul};
+/// use metric::length::metric::Meter;
+/// use metric::mass::metric::Kilogram;
+/// use metric::time::Second;
+///
+/// fn main() {
+///     let force: unit!(kg * m / s^2) = Kilogram(2.0) * (Meter(3.0) / (Second(1.0) * Second(1.0)));
+///     let _: Mul<Kilogram, Div<Meter, Mul<Second, Second>>> = force;
+///     let _: unit!(Kilogram / (m * s^2)) = Div::from_value(1.0);
+///     let _: unit!(m * s^-1) = Meter(1.0) / Second(1.0);
+///     let _: unit!(s^-1) = 1.0 / Second(1.0);
+///     let _: unit!(1 / (m * s)) = 1.0 / (Meter(1.0) * Second(1.0));
+/// }
+/// ```
+///
+/// Symbols are the same as those the units are printed with, except for `t` (metric ton), `ug` and `us` (micro),
+/// `degC`, `degF`, `day`, `h`, `deg`, `arcmin`, `arcsec`, `sqdeg`, `ftlbf`, `ohm`, `kohm` and `uF`.
+///
+/// A symbol always wins over a type of the same name, so a type parameter named `A`, `K` or `N` would be read as
+/// `Ampere`, `Kelvin` or `Newton`. Any type written in braces, such as `{A}`, is taken as it is instead.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::composite::Div;
+/// use metric::length::metric::Meter;
+/// use metric::time::Second;
+///
+/// type PerSecond<A> = unit!({A} / s);
+///
+/// fn main() {
+///     let _: PerSecond<Meter> = Meter(1.0) / Second(1.0);
+///     let _: unit!({Div<Meter, Second>} / s) = Div::from_value(9.81);
+/// }
+/// ```
+#[macro_export]
+macro_rules! unit {
+    ($($expr:tt)+) => {
+        $crate::__unit_expr!(@term [] [] * $($expr)+)
+    };
+}
+
+/// Creates a quantity from a number and a unit expression, as accepted by `unit!`.
+///
+/// The number is a literal, optionally negated, or an expression in parentheses, and is taken in the unit written, so
+/// `qty!(9.81 m/s^2)` is `Div<Meter, Mul<Second, Second>>` holding `Meter(9.81)`. The macro may be used in `const`
+/// contexts.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::length::metric::Meter;
+/// use metric::time::Second;
+///
+/// const GRAVITY: unit!(m / s^2) = qty!(9.81 m/s^2);
+///
+/// fn main() {
+///     let fall: Meter = GRAVITY.integrate(Second(2.0)).multiply(Second(1.0));
+///     assert_eq!(fall, Meter(19.62));
+///     assert_eq!(qty!(-40 degC), qty!(-40 degF));
+///     assert_eq!(qty!((1.0 / 2.0) km), Meter(500.0));
+/// }
+/// ```
+#[macro_export]
+macro_rules! qty {
+    (- $value:literal $($unit:tt)+) => {
+        $crate::composite::from_raw_value::<$crate::unit!($($unit)+)>(-($value as f64))
+    };
+    ($value:literal $($unit:tt)+) => {
+        $crate::composite::from_raw_value::<$crate::unit!($($unit)+)>($value as f64)
+    };
+    (($value:expr) $($unit:tt)+) => {
+        $crate::composite::from_raw_value::<$crate::unit!($($unit)+)>(($value) as f64)
+    };
+}
+
+//parses a unit expression into the lists of units multiplied and divided by, then builds the composite from them
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_expr {
+    (@term $num:tt $den:tt $op:tt ($($group:tt)+) $($rest:tt)*) => {
+        $crate::__unit_expr!(@factor $num $den $op $crate::unit!($($group)+); $($rest)*)
+    };
+    //a type in braces is taken as it is, without looking it up as a symbol
+    (@term $num:tt $den:tt $op:tt {$factor:ty} $($rest:tt)*) => {
+        $crate::__unit_expr!(@factor $num $den $op $factor; $($rest)*)
+    };
+    //`1` is the numerator of reciprocals such as `1 / s`, and adds no unit
+    (@term $num:tt $den:tt * 1 $($rest:tt)*) => {
+        $crate::__unit_expr!(@next $num $den $($rest)*)
+    };
+    //`dyn` is a keyword from the 2018 edition on, so it is not matched as an identifier
+    (@term $num:tt $den:tt $op:tt dyn $($rest:tt)*) => {
+        $crate::__unit_expr!(@factor $num $den $op $crate::__unit_symbol!(dyn); $($rest)*)
+    };
+    (@term $num:tt $den:tt $op:tt $sym:ident $($rest:tt)*) => {
+        $crate::__unit_expr!(@path $num $den $op [$sym] $($rest)*)
+    };
+    (@term $num:tt $den:tt $op:tt $($rest:tt)*) => {
+        compile_error!(concat!("expected a unit in unit expression, found `", stringify!($($rest)*), "`"))
+    };
+
+    (@path $num:tt $den:tt $op:tt [$($path:tt)+] :: $seg:ident $($rest:tt)*) => {
+        $crate::__unit_expr!(@path $num $den $op [$($path)+ :: $seg] $($rest)*)
+    };
+    (@path $num:tt $den:tt $op:tt [$($path:tt)+] $($rest:tt)*) => {
+        $crate::__unit_expr!(@factor $num $den $op $crate::__unit_symbol!($($path)+); $($rest)*)
+    };
+
+    //wraps the factor into a single token tree
+    (@factor $num:tt $den:tt $op:tt $factor:ty; $($rest:tt)*) => {
+        $crate::__unit_expr!(@exp $num $den $op $factor $($rest)*)
+    };
+
+    (@exp $num:tt $den:tt * $factor:tt ^ - $exp:tt $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow $num $den / $factor $exp; $($rest)*)
+    };
+    (@exp $num:tt $den:tt / $factor:tt ^ - $exp:tt $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow $num $den * $factor $exp; $($rest)*)
+    };
+    (@exp $num:tt $den:tt $op:tt $factor:tt ^ $exp:tt $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow $num $den $op $factor $exp; $($rest)*)
+    };
+    (@exp $num:tt $den:tt $op:tt $factor:tt $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow $num $den $op $factor 1; $($rest)*)
+    };
+
+    //pushes the factor once per power, counting down to zero
+    (@pow $num:tt $den:tt $op:tt $factor:tt 0; $($rest:tt)*) => {
+        $crate::__unit_expr!(@next $num $den $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 1; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 0; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 2; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 1; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 3; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 2; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 4; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 3; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 5; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 4; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 6; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 5; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 7; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 6; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 8; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 7; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt 9; $($rest:tt)*) => {
+        $crate::__unit_expr!(@push $num $den $op $factor 8; $($rest)*)
+    };
+    (@pow $num:tt $den:tt $op:tt $factor:tt $exp:tt; $($rest:tt)*) => {
+        compile_error!(concat!("unsupported exponent `", stringify!($exp), "` in unit expression, expected -9 to 9"))
+    };
+
+    (@push [$($num:tt)*] $den:tt * $factor:tt $exp:tt; $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow [$($num)* $factor] $den * $factor $exp; $($rest)*)
+    };
+    (@push $num:tt [$($den:tt)*] / $factor:tt $exp:tt; $($rest:tt)*) => {
+        $crate::__unit_expr!(@pow $num [$($den)* $factor] / $factor $exp; $($rest)*)
+    };
+
+    (@next $num:tt $den:tt) => {
+        $crate::__unit_expr!(@build $num $den)
+    };
+    (@next $num:tt $den:tt * $($rest:tt)+) => {
+        $crate::__unit_expr!(@term $num $den * $($rest)+)
+    };
+    (@next $num:tt $den:tt / $($rest:tt)+) => {
+        $crate::__unit_expr!(@term $num $den / $($rest)+)
+    };
+    (@next $num:tt $den:tt $($rest:tt)+) => {
+        compile_error!(concat!("expected `*`, `/` or `^` in unit expression, found `", stringify!($($rest)+), "`"))
+    };
+
+    (@build [$num:tt] []) => {
+        $num
+    };
+    (@build [$num:tt $($nums:tt)+] $den:tt) => {
+        $crate::composite::Mul<$num, $crate::__unit_expr!(@build [$($nums)+] $den)>
+    };
+    (@build [$num:tt] [$($den:tt)+]) => {
+        $crate::composite::Div<$num, $crate::__unit_expr!(@build [$($den)+] [])>
+    };
+    (@build [] []) => {
+        $crate::composite::One
+    };
+    (@build [] $den:tt) => {
+        $crate::composite::Per<$crate::__unit_expr!(@build $den [])>
+    };
+}
+
+//maps a unit symbol to its type, passing any other path through unchanged
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_symbol {
+    (km) => { $crate::length::metric::Kilometer };
+    (m) => { $crate::length::metric::Meter };
+    (cm) => { $crate::length::metric::Centimeter };
+    (mm) => { $crate::length::metric::Millimeter };
+    (um) => { $crate::length::metric::Micrometer };
+    (nm) => { $crate::length::metric::Nanometer };
+    (pm) => { $crate::length::metric::Picometer };
+    (fm) => { $crate::length::metric::Femtometer };
+    (Mm) => { $crate::length::metric::Megameter };
+    (Gm) => { $crate::length::metric::Gigameter };
+    (am) => { $crate::length::metric::Attometer };
+    (in) => { $crate::length::imperial::Inch };
+    (ft) => { $crate::length::imperial::Foot };
+    (yd) => { $crate::length::imperial::Yard };
+    (mi) => { $crate::length::imperial::Mile };
+    (AU) => { $crate::length::astronomical::AU };
+    (ly) => { $crate::length::astronomical::Lightyear };
+
+    (t) => { $crate::mass::metric::Ton };
+    (kg) => { $crate::mass::metric::Kilogram };
+    (g) => { $crate::mass::metric::Gram };
+    (cg) => { $crate::mass::metric::Centigram };
+    (mg) => { $crate::mass::metric::Milligram };
+    (ug) => { $crate::mass::metric::Microgram };
+    (ng) => { $crate::mass::metric::Nanogram };
+    (lb) => { $crate::mass::imperial::Pound };
+    (oz) => { $crate::mass::imperial::Ounce };
+
+    (s) => { $crate::time::Second };
+    (ms) => { $crate::time::Millisecond };
+    (us) => { $crate::time::Microsecond };
+    (ns) => { $crate::time::Nanosecond };
+    (ps) => { $crate::time::Picosecond };
+    (fs) => { $crate::time::Femtosecond };
+    (min) => { $crate::time::Minute };
+    (h) => { $crate::time::Hour };
+    (hr) => { $crate::time::Hour };
+    (d) => { $crate::time::Day };
+    (day) => { $crate::time::Day };
+    (yr) => { $crate::time::Year };
+
+    (K) => { $crate::temperature::Kelvin };
+    (degC) => { $crate::temperature::Celsius };
+    (degF) => { $crate::temperature::Fahrenheit };
+
+    (rad) => { $crate::angle::Radian };
+    (mrad) => { $crate::angle::Milliradian };
+    (deg) => { $crate::angle::Degree };
+    (gon) => { $crate::angle::Gradian };
+    (tr) => { $crate::angle::Turn };
+    (arcmin) => { $crate::angle::Arcminute };
+    (arcsec) => { $crate::angle::Arcsecond };
+    (sr) => { $crate::solid_angle::Steradian };
+    (sqdeg) => { $crate::solid_angle::SquareDegree };
+
+    (N) => { $crate::force::Newton };
+    (kN) => { $crate::force::Kilonewton };
+    (dyn) => { $crate::force::Dyne };
+    (lbf) => { $crate::force::PoundForce };
+    (kgf) => { $crate::force::KilogramForce };
+    (pdl) => { $crate::force::Poundal };
+
+    (J) => { $crate::energy::Joule };
+    (kJ) => { $crate::energy::Kilojoule };
+    (cal) => { $crate::energy::Calorie };
+    (kcal) => { $crate::energy::Kilocalorie };
+    (Wh) => { $crate::energy::WattHour };
+    (kWh) => { $crate::energy::KilowattHour };
+    (BTU) => { $crate::energy::BritishThermalUnit };
+    (eV) => { $crate::energy::Electronvolt };
+    (erg) => { $crate::energy::Erg };
+    (ftlbf) => { $crate::energy::FootPound };
+
+    (W) => { $crate::power::Watt };
+    (kW) => { $crate::power::Kilowatt };
+    (MW) => { $crate::power::Megawatt };
+    (hp) => { $crate::power::MechanicalHorsepower };
+    (PS) => { $crate::power::MetricHorsepower };
+    (TR) => { $crate::power::TonOfRefrigeration };
+
+    (Pa) => { $crate::pressure::Pascal };
+    (kPa) => { $crate::pressure::Kilopascal };
+    (MPa) => { $crate::pressure::Megapascal };
+    (bar) => { $crate::pressure::Bar };
+    (mbar) => { $crate::pressure::Millibar };
+    (atm) => { $crate::pressure::Atmosphere };
+    (psi) => { $crate::pressure::Psi };
+    (Torr) => { $crate::pressure::Torr };
+    (mmHg) => { $crate::pressure::MillimeterOfMercury };
+    (inH2O) => { $crate::pressure::InchOfWater };
+    (kPag) => { $crate::pressure::KilopascalGauge };
+    (barg) => { $crate::pressure::BarGauge };
+    (psig) => { $crate::pressure::PsiGauge };
+
+    (A) => { $crate::electrical::Ampere };
+    (mA) => { $crate::electrical::Milliampere };
+    (V) => { $crate::electrical::Volt };
+    (ohm) => { $crate::electrical::Ohm };
+    (kohm) => { $crate::electrical::Kiloohm };
+    (S) => { $crate::electrical::Siemens };
+    (C) => { $crate::electrical::Coulomb };
+    (F) => { $crate::electrical::Farad };
+    (uF) => { $crate::electrical::Microfarad };
+    (H) => { $crate::electrical::Henry };
+    (nH) => { $crate::electrical::Nanohenry };
+
+    ($($path:tt)+) => { $($path)+ };
+}
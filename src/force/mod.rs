@@ -1,7 +1,100 @@
+//! Units of force.
+//!
+//! Every unit of force converts to and from any composite with the dimension of a force, such as the
+//! `Mul<Kilogram, Div<Meter, Mul<Second, Second>>>` produced by multiplying a mass by an acceleration. The order of
+//! the factors does not matter, so an acceleration times a mass converts just as well.
+//!
+//! ```
+//! use metric::composite::{Div, Mul};
+//! use metric::force::{Kilonewton, Newton, PoundForce};
+//! use metric::length::metric::Meter;
+//! use metric::mass::metric::Kilogram;
+//! use metric::time::Second;
+//!
+//! let weight: Newton = (Kilogram(100.0) * (Meter(9.80665) / (Second(1.0) * Second(1.0)))).into();
+//! assert_eq!(weight, Newton(980.665));
+//! assert_eq!(Newton::from((Meter(9.80665) / (Second(1.0) * Second(1.0))) * Kilogram(100.0)), weight);
+//! assert_eq!(Kilonewton::from(weight), Kilonewton::new(0.980665));
+//! let composite: Mul<Kilogram, Div<Meter, Mul<Second, Second>>> = PoundForce(1.0).into();
+//! assert_eq!(composite.0, Kilogram(4.4482216152605));
+//! ```
 
-use composite::*;
-use mass::metric::Kilogram;
-use length::metric::Meter;
-use time::Second;
+use prefix::{Kilo, Prefixed};
 
-pub type Newton = Mul<Kilogram, Div<Meter, Mul<Second, Second>>>;
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Newton(pub f64);
+pub type Newtons = Newton;
+
+impl_full_unit!(Newton);
+impl_unit_debug!(Newton => "N");
+impl_quantity!(Newton => Newton, 1.0);
+impl_composite_base!(Newton);
+impl_from_composite!(Newton);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Dyne(pub f64);
+pub type Dynes = Dyne;
+
+impl_full_unit!(Dyne);
+impl_unit_debug!(Dyne => "dyn");
+impl_quantity!(Dyne => Newton, 1e-5);
+impl_composite_base!(Dyne);
+impl_from_composite!(Dyne);
+
+impl_from_quantity!(Newton, Dyne);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct PoundForce(pub f64);
+pub type PoundsForce = PoundForce;
+
+impl_full_unit!(PoundForce);
+impl_unit_debug!(PoundForce => "lbf");
+impl_quantity!(PoundForce => Newton, 4.4482216152605);
+impl_composite_base!(PoundForce);
+impl_from_composite!(PoundForce);
+
+impl_from_quantity!(PoundForce, Newton);
+impl_from_quantity!(PoundForce, Dyne);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct KilogramForce(pub f64);
+pub type KilogramsForce = KilogramForce;
+
+impl_full_unit!(KilogramForce);
+impl_unit_debug!(KilogramForce => "kgf");
+impl_quantity!(KilogramForce => Newton, 9.80665);
+impl_composite_base!(KilogramForce);
+impl_from_composite!(KilogramForce);
+
+impl_from_quantity!(KilogramForce, Newton);
+impl_from_quantity!(KilogramForce, Dyne);
+impl_from_quantity!(KilogramForce, PoundForce);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Poundal(pub f64);
+pub type Poundals = Poundal;
+
+impl_full_unit!(Poundal);
+impl_unit_debug!(Poundal => "pdl");
+impl_quantity!(Poundal => Newton, 0.138254954376);
+impl_composite_base!(Poundal);
+impl_from_composite!(Poundal);
+
+impl_from_quantity!(Poundal, Newton);
+impl_from_quantity!(Poundal, Dyne);
+impl_from_quantity!(Poundal, PoundForce);
+impl_from_quantity!(Poundal, KilogramForce);
+
+/// A thousand newtons, as a prefixed `Newton`.
+pub type Kilonewton = Prefixed<Kilo, Newton>;
+pub type Kilonewtons = Kilonewton;
@@ -1,85 +1,177 @@
+//the helpers below expand their input only when the matching feature of this crate is enabled, since `#[cfg]`
+//attributes inside exported macros would otherwise be evaluated against the features of the calling crate
+#[cfg(any(feature = "std", feature = "libm"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __if_math {
+    ($($item:item)*) => { $($item)* }
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __if_math {
+    ($($item:item)*) => {}
+}
+
+#[cfg(feature = "approx")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __if_approx {
+    ($($item:item)*) => { $($item)* }
+}
+
+#[cfg(not(feature = "approx"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __if_approx {
+    ($($item:item)*) => {}
+}
+
+//the unit macros below take either a unit, or with `@generic`, the generic parameters of a unit type, the trait
+//that right-hand sides and other arguments convert through and the function converting them, e.g.
+//`@generic [P: Prefix, Q: Quantity] Prefixed<P, Q>, Quantity<Base = Q::Base>, convert_const`
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_partial_ord {
     ($impl_type:tt) => {
-        impl<T> PartialEq<T> for $impl_type
-            where T: Copy + Into<$impl_type>
+        $crate::impl_partial_ord!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                                  $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> PartialEq<T> for $impl_type
+            where T: Copy + $rhs
         {
             fn eq(&self, other: &T) -> bool {
-                let other_t: T = *other;
-                let other: Self = other_t.into();
+                let other: Self = $conv(*other);
                 self.0.eq(&other.0)
             }
         }
-        impl<T> PartialOrd<T> for $impl_type
-            where T: Copy + Into<$impl_type>
+        impl<T, $($gen)*> PartialOrd<T> for $impl_type
+            where T: Copy + $rhs
         {
-            fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
-                let other_t: T = *other;
-                let other: Self = other_t.into();
+            fn partial_cmp(&self, other: &T) -> Option<$crate::__core::cmp::Ordering> {
+                let other: Self = $conv(*other);
                 self.0.partial_cmp(&other.0)
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_partial_ord {
+    ($composite:ident) => {
+        impl<T, U, V> PartialEq<V> for $composite<T, U>
+            where $composite<T, U>: $crate::composite::Quantity,
+                  V: $crate::composite::Quantity,
+                  V::Base: $crate::composite::Compare<<$composite<T, U> as $crate::composite::Quantity>::Base,
+                                                    Result = $crate::composite::Same>
+        {
+            fn eq(&self, other: &V) -> bool {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(other));
+                $crate::composite::Unit::inner(self).eq(&other)
+            }
+        }
+        impl<T, U, V> PartialOrd<V> for $composite<T, U>
+            where $composite<T, U>: $crate::composite::Quantity,
+                  V: $crate::composite::Quantity,
+                  V::Base: $crate::composite::Compare<<$composite<T, U> as $crate::composite::Quantity>::Base,
+                                                    Result = $crate::composite::Same>
+        {
+            fn partial_cmp(&self, other: &V) -> Option<$crate::__core::cmp::Ordering> {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(other));
+                $crate::composite::Unit::inner(self).partial_cmp(&other)
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_add {
     ($impl_type:tt) => {
-        impl<T> core::ops::Add<T> for $impl_type
-            where T: Into<$impl_type>
+        $crate::impl_add!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                          $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::ops::Add<T> for $impl_type
+            where T: $rhs
         {
             type Output = $impl_type;
 
             fn add(self, other: T) -> Self::Output {
-                let other: Self = other.into();
-                $impl_type(self.0 + other.0)
+                let other: Self = $conv(other);
+                $crate::composite::Unit::new(self.0 + other.0)
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_addassign {
     ($impl_type:tt) => {
-        impl<T> core::ops::AddAssign<T> for $impl_type
-            where T: Into<$impl_type>
+        $crate::impl_addassign!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                                $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::ops::AddAssign<T> for $impl_type
+            where T: $rhs
         {
             fn add_assign(&mut self, other: T) {
-                let other: Self = other.into();
+                let other: Self = $conv(other);
                 self.0 += other.0;
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_sub {
     ($impl_type:tt) => {
-        impl<T> core::ops::Sub<T> for $impl_type
-            where T: Into<$impl_type>
+        $crate::impl_sub!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                          $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::ops::Sub<T> for $impl_type
+            where T: $rhs
         {
             type Output = $impl_type;
 
             fn sub(self, other: T) -> Self::Output {
-                let other: Self = other.into();
-                $impl_type(self.0 - other.0)
+                let other: Self = $conv(other);
+                $crate::composite::Unit::new(self.0 - other.0)
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_subassign {
     ($impl_type:tt) => {
-        impl<T> core::ops::SubAssign<T> for $impl_type
-            where T: Into<$impl_type>
+        $crate::impl_subassign!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                                $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::ops::SubAssign<T> for $impl_type
+            where T: $rhs
         {
             fn sub_assign(&mut self, other: T) {
-                let other: Self = other.into();
+                let other: Self = $conv(other);
                 self.0 -= other.0;
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_mul {
     ($impl_type:tt) => {
-        impl<T> core::ops::Mul<T> for $impl_type
+        impl<T> $crate::__core::ops::Mul<T> for $impl_type
             where T: Into<$impl_type>
         {
             type Output = $impl_type;
@@ -92,9 +184,11 @@ macro_rules! impl_mul {
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_mulassign {
     ($impl_type:tt) => {
-        impl<T> core::ops::MulAssign<T> for $impl_type
+        impl<T> $crate::__core::ops::MulAssign<T> for $impl_type
             where T: Into<$impl_type>
         {
             fn mul_assign(&mut self, other: T) {
@@ -105,308 +199,1323 @@ macro_rules! impl_mulassign {
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_div_same {
     ($impl_type:tt) => {
-        impl<T> core::ops::Div<T> for $impl_type
-            where T: Into<$impl_type>
+        impl $crate::composite::DivSame for $impl_type {}
+    }
+}
+
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_rem {
+    ($impl_type:tt) => {
+        $crate::impl_rem!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                          $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::ops::Rem<T> for $impl_type
+            where T: $rhs
         {
-            type Output = f64;
+            type Output = $impl_type;
 
-            fn div(self, other: T) -> Self::Output {
-                let other: Self = other.into();
-                self.0 / other.0
+            fn rem(self, other: T) -> Self::Output {
+                let other: Self = $conv(other);
+                $crate::composite::Unit::new(self.0 % other.0)
+            }
+        }
+        impl<T, $($gen)*> $crate::__core::ops::RemAssign<T> for $impl_type
+            where T: $rhs
+        {
+            fn rem_assign(&mut self, other: T) {
+                let other: Self = $conv(other);
+                self.0 %= other.0;
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_consts {
+    ($impl_type:tt) => {
+        $crate::impl_consts!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        impl<$($gen)*> $impl_type {
+            /// A value of zero in this unit.
+            pub const ZERO: $impl_type = $crate::composite::from_raw_value(0.0);
+            /// A value of one in this unit.
+            pub const ONE: $impl_type = $crate::composite::from_raw_value(1.0);
+        }
+        impl<$($gen)*> Default for $impl_type {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+    }
+}
 
-macro_rules! impl_mul_scalar {
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_sum {
+    ($impl_type:tt) => {
+        $crate::impl_sum!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                          $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<T, $($gen)*> $crate::__core::iter::Sum<T> for $impl_type
+            where T: $rhs
+        {
+            fn sum<I>(iter: I) -> Self
+                where I: Iterator<Item = T>
+            {
+                iter.fold(Self::ZERO, |sum, other| sum + other)
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_neg {
     ($impl_type:tt) => {
-        impl core::ops::Mul<f64> for $impl_type
+        $crate::impl_neg!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        impl<$($gen)*> $crate::__core::ops::Neg for $impl_type
         {
             type Output = $impl_type;
 
-            fn mul(self, other: f64) -> Self::Output {
-                $impl_type(self.0 * other)
+            fn neg(self) -> Self::Output {
+                $crate::composite::Unit::new(-self.0)
             }
         }
-        impl core::ops::Mul<$impl_type> for f64
+        impl<'a, $($gen)*> $crate::__core::ops::Neg for &'a $impl_type
         {
             type Output = $impl_type;
 
-            fn mul(self, other: $impl_type) -> Self::Output {
-                $impl_type(self * other.0)
+            fn neg(self) -> Self::Output {
+                $crate::composite::Unit::new(-self.0)
             }
         }
-        impl core::ops::Mul<i64> for $impl_type
+    }
+}
+
+//impl_ref_ops forwards every operator on `&unit` to the by-value impl. right-hand references are covered by the
+//`From<&T>` conversions, and by `Operand` for `*` and `/`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_ref_ops {
+    ($impl_type:tt) => {
+        $crate::impl_ref_ops!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        $crate::impl_ref_ops!(@op [$($gen)*] $impl_type, Add, add);
+        $crate::impl_ref_ops!(@op [$($gen)*] $impl_type, Sub, sub);
+        $crate::impl_ref_ops!(@op [$($gen)*] $impl_type, Mul, mul);
+        $crate::impl_ref_ops!(@op [$($gen)*] $impl_type, Div, div);
+        $crate::impl_ref_ops!(@op [$($gen)*] $impl_type, Rem, rem);
+
+        impl<'a, $($gen)*> From<&'a $impl_type> for $impl_type {
+            fn from(f: &'a $impl_type) -> Self {
+                *f
+            }
+        }
+    };
+    (@op [$($gen:tt)*] $impl_type:ty, $op:ident, $method:ident) => {
+        impl<'a, T, $($gen)*> $crate::__core::ops::$op<T> for &'a $impl_type
+            where $impl_type: $crate::__core::ops::$op<T>
         {
-            type Output = $impl_type;
+            type Output = <$impl_type as $crate::__core::ops::$op<T>>::Output;
 
-            fn mul(self, other: i64) -> Self::Output {
-                $impl_type(self.0 * other as f64)
+            fn $method(self, other: T) -> Self::Output {
+                $crate::__core::ops::$op::$method(*self, other)
             }
         }
-        impl core::ops::Mul<$impl_type> for i64
+    }
+}
+
+//impl_for_scalars invokes the given macro once for every primitive scalar type, along with a conversion to `f64`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_for_scalars {
+    ($mac:ident!()) => {
+        $crate::$mac!(f64, |s| s);
+        $crate::$mac!(f32, f64::from);
+        $crate::$mac!(i64, |s| s as f64);
+        $crate::$mac!(i32, f64::from);
+        $crate::$mac!(u64, |s| s as f64);
+        $crate::$mac!(u32, f64::from);
+        $crate::$mac!(usize, |s| s as f64);
+        $crate::$mac!(isize, |s| s as f64);
+    };
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, f64, |s| s);
+        $crate::$mac!($($args)*, f32, f64::from);
+        $crate::$mac!($($args)*, i64, |s| s as f64);
+        $crate::$mac!($($args)*, i32, f64::from);
+        $crate::$mac!($($args)*, u64, |s| s as f64);
+        $crate::$mac!($($args)*, u32, f64::from);
+        $crate::$mac!($($args)*, usize, |s| s as f64);
+        $crate::$mac!($($args)*, isize, |s| s as f64);
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_mul_scalar {
+    ($impl_type:tt) => {
+        $crate::impl_mul_scalar!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $scalar:ty, $to_f64:expr) => {
+        impl<$($gen)*> $crate::__core::ops::Mul<$impl_type> for $scalar
         {
             type Output = $impl_type;
 
             fn mul(self, other: $impl_type) -> Self::Output {
-                $impl_type(self as f64 * other.0)
+                $crate::composite::Unit::new($to_f64(self) * other.0)
             }
         }
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        $crate::impl_for_scalars!(impl_mul_scalar!(@generic [$($gen)*] $impl_type));
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_mulassign_scalar {
     ($impl_type:tt) => {
-        impl core::ops::MulAssign<f64> for $impl_type
+        $crate::impl_mulassign_scalar!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $scalar:ty, $to_f64:expr) => {
+        impl<$($gen)*> $crate::__core::ops::MulAssign<$scalar> for $impl_type
         {
-            fn mul_assign(&mut self, other: f64) {
-                self.0 *= other;
+            fn mul_assign(&mut self, other: $scalar) {
+                self.0 *= $to_f64(other);
             }
         }
-        impl core::ops::MulAssign<i64> for $impl_type
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        $crate::impl_for_scalars!(impl_mulassign_scalar!(@generic [$($gen)*] $impl_type));
+    }
+}
+
+//impl_scalar_rhs lets a scalar multiply or divide any unit on its left, see `composite::MulRhs`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_scalar_rhs {
+    ($scalar:ty, $to_f64:expr) => {
+        impl<L> $crate::composite::MulRhs<L> for $scalar
+            where L: $crate::composite::Scalable
         {
-            fn mul_assign(&mut self, other: i64) {
-                self.0 *= other as f64;
+            type Output = L;
+
+            #[inline(always)]
+            fn mul_rhs(self, l: L) -> L {
+                $crate::composite::Unit::new($crate::composite::Unit::inner(&l) * $to_f64(self))
+            }
+        }
+        impl<L> $crate::composite::DivRhs<L> for $scalar
+            where L: $crate::composite::Scalable
+        {
+            type Output = L;
+
+            #[inline(always)]
+            fn div_rhs(self, l: L) -> L {
+                $crate::composite::Unit::new($crate::composite::Unit::inner(&l) / $to_f64(self))
             }
         }
     }
 }
 
-macro_rules! impl_div_scalar {
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_divassign_scalar {
     ($impl_type:tt) => {
-        impl core::ops::Div<f64> for $impl_type
+        $crate::impl_divassign_scalar!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $scalar:ty, $to_f64:expr) => {
+        impl<$($gen)*> $crate::__core::ops::DivAssign<$scalar> for $impl_type
         {
-            type Output = $impl_type;
+            fn div_assign(&mut self, other: $scalar) {
+                self.0 /= $to_f64(other);
+            }
+        }
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        $crate::impl_for_scalars!(impl_divassign_scalar!(@generic [$($gen)*] $impl_type));
+    }
+}
 
-            fn div(self, other: f64) -> Self::Output {
-                $impl_type(self.0 / other)
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_ops {
+    (@sum_ref $composite:ident, $other:ident) => {
+        impl<'a, T, U, A, B> $crate::__core::iter::Sum<&'a $crate::composite::$other<A, B>> for $composite<T, U>
+            where $crate::composite::$other<A, B>: Copy + 'a,
+                  $composite<T, U>: $crate::__core::iter::Sum<$crate::composite::$other<A, B>>
+        {
+            fn sum<I>(iter: I) -> Self
+                where I: Iterator<Item = &'a $crate::composite::$other<A, B>>
+            {
+                iter.copied().sum()
+            }
+        }
+    };
+    ($composite:ident) => {
+        impl<T, U> $crate::__core::ops::AddAssign for $composite<T, U>
+            where T: $crate::__core::ops::AddAssign<T>
+        {
+            fn add_assign(&mut self, other: $composite<T, U>) {
+                self.0 += other.0;
             }
         }
-        impl core::ops::Div<i64> for $impl_type
+
+        impl<T, U> $crate::__core::ops::SubAssign for $composite<T, U>
+            where T: $crate::__core::ops::SubAssign<T>
         {
-            type Output = $impl_type;
+            fn sub_assign(&mut self, other: $composite<T, U>) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl<T, U> $crate::__core::ops::Rem for $composite<T, U>
+            where T: $crate::__core::ops::Rem<T, Output = T>
+        {
+            type Output = $composite<T, U>;
+
+            fn rem(self, other: $composite<T, U>) -> Self::Output {
+                $composite(self.0 % other.0, self.1)
+            }
+        }
+
+        impl<T, U> $crate::__core::ops::RemAssign for $composite<T, U>
+            where T: $crate::__core::ops::RemAssign<T>
+        {
+            fn rem_assign(&mut self, other: $composite<T, U>) {
+                self.0 %= other.0;
+            }
+        }
 
-            fn div(self, other: i64) -> Self::Output {
-                $impl_type(self.0 / other as f64)
+        impl<T, U> Default for $composite<T, U>
+            where T: $crate::composite::Unit
+        {
+            fn default() -> Self {
+                $composite(T::ZERO, $crate::__core::marker::PhantomData)
+            }
+        }
+
+        impl<'a, T, U> From<&'a $composite<T, U>> for $composite<T, U>
+            where $composite<T, U>: Copy
+        {
+            fn from(f: &'a $composite<T, U>) -> Self {
+                *f
+            }
+        }
+
+        impl<T, U, V> $crate::__core::iter::Sum<V> for $composite<T, U>
+            where T: $crate::composite::Unit,
+                  $composite<T, U>: $crate::composite::Quantity,
+                  V: $crate::composite::Quantity,
+                  V::Base: $crate::composite::Compare<<$composite<T, U> as $crate::composite::Quantity>::Base,
+                                                    Result = $crate::composite::Same>
+        {
+            fn sum<I>(iter: I) -> Self
+                where I: Iterator<Item = V>
+            {
+                let sum = iter.fold(0.0, |sum, other| {
+                    sum + $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other))
+                });
+                $composite(T::new(sum), $crate::__core::marker::PhantomData)
+            }
+        }
+        $crate::impl_composite_ops!(@sum_ref $composite, Mul);
+        $crate::impl_composite_ops!(@sum_ref $composite, Div);
+
+        impl<T, U> $crate::__core::ops::Neg for $composite<T, U>
+            where T: $crate::__core::ops::Neg<Output = T>
+        {
+            type Output = $composite<T, U>;
+
+            fn neg(self) -> Self::Output {
+                $composite(-self.0, self.1)
+            }
+        }
+
+        $crate::impl_composite_ops!($composite, Add, add, AddAssign, add_assign);
+        $crate::impl_composite_ops!($composite, Sub, sub, SubAssign, sub_assign);
+        $crate::impl_composite_ops!($composite, Rem, rem, RemAssign, rem_assign);
+        $crate::impl_composite_ops!($composite, Mul, mul);
+        $crate::impl_composite_ops!($composite, Div, div);
+
+        impl<'a, T, U, V> $crate::__core::ops::Mul<&'a V> for $composite<T, U>
+            where V: Copy,
+                  $composite<T, U>: $crate::__core::ops::Mul<V>
+        {
+            type Output = <$composite<T, U> as $crate::__core::ops::Mul<V>>::Output;
+
+            fn mul(self, other: &'a V) -> Self::Output {
+                self * *other
+            }
+        }
+
+        impl<'a, T, U, V> $crate::__core::ops::Div<&'a V> for $composite<T, U>
+            where V: Copy,
+                  $composite<T, U>: $crate::__core::ops::Div<V>
+        {
+            type Output = <$composite<T, U> as $crate::__core::ops::Div<V>>::Output;
+
+            fn div(self, other: &'a V) -> Self::Output {
+                self / *other
+            }
+        }
+
+        impl<'a, T, U> $crate::__core::ops::Neg for &'a $composite<T, U>
+            where T: Copy,
+                  $composite<T, U>: Copy + $crate::__core::ops::Neg
+        {
+            type Output = <$composite<T, U> as $crate::__core::ops::Neg>::Output;
+
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+    };
+    //operators whose right-hand side is the same composite also accept it by reference
+    ($composite:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<'a, T, U> $crate::__core::ops::$op<&'a $composite<T, U>> for $composite<T, U>
+            where $composite<T, U>: Copy + $crate::__core::ops::$op
+        {
+            type Output = <$composite<T, U> as $crate::__core::ops::$op>::Output;
+
+            fn $method(self, other: &'a $composite<T, U>) -> Self::Output {
+                $crate::__core::ops::$op::$method(self, *other)
+            }
+        }
+
+        impl<'a, T, U> $crate::__core::ops::$op_assign<&'a $composite<T, U>> for $composite<T, U>
+            where $composite<T, U>: Copy + $crate::__core::ops::$op_assign
+        {
+            fn $method_assign(&mut self, other: &'a $composite<T, U>) {
+                $crate::__core::ops::$op_assign::$method_assign(self, *other)
+            }
+        }
+
+        $crate::impl_composite_ops!($composite, $op, $method);
+    };
+    ($composite:ident, $op:ident, $method:ident) => {
+        impl<'a, T, U, V> $crate::__core::ops::$op<V> for &'a $composite<T, U>
+            where $composite<T, U>: Copy + $crate::__core::ops::$op<V>
+        {
+            type Output = <$composite<T, U> as $crate::__core::ops::$op<V>>::Output;
+
+            fn $method(self, other: V) -> Self::Output {
+                $crate::__core::ops::$op::$method(*self, other)
             }
         }
     }
 }
 
-macro_rules! impl_divassign_scalar {
-    ($impl_type:tt) => {
-        impl core::ops::DivAssign<f64> for $impl_type
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_scalar_ops {
+    ($composite:ident) => {
+        $crate::impl_for_scalars!(impl_composite_scalar_ops!($composite));
+    };
+    ($composite:ident, $scalar:ty, $to_f64:expr) => {
+        impl<T, U> $crate::__core::ops::Mul<$scalar> for $composite<T, U>
+            where T: $crate::__core::ops::Mul<f64, Output = T>
         {
-            fn div_assign(&mut self, other: f64) {
-                self.0 /= other;
+            type Output = $composite<T, U>;
+
+            fn mul(self, other: $scalar) -> Self::Output {
+                $composite(self.0 * $to_f64(other), self.1)
+            }
+        }
+        impl<T, U> $crate::__core::ops::Mul<$composite<T, U>> for $scalar
+            where T: $crate::__core::ops::Mul<f64, Output = T>
+        {
+            type Output = $composite<T, U>;
+
+            fn mul(self, other: $composite<T, U>) -> Self::Output {
+                $composite(other.0 * $to_f64(self), other.1)
+            }
+        }
+
+        impl<T, U> $crate::__core::ops::MulAssign<$scalar> for $composite<T, U>
+            where T: $crate::__core::ops::MulAssign<f64>
+        {
+            fn mul_assign(&mut self, other: $scalar) {
+                self.0 *= $to_f64(other);
             }
         }
-        impl core::ops::DivAssign<i64> for $impl_type
+
+        impl<T, U> $crate::__core::ops::Div<$scalar> for $composite<T, U>
+            where T: $crate::__core::ops::Div<f64, Output = T>
         {
-            fn div_assign(&mut self, other: i64) {
-                self.0 /= other as f64;
+            type Output = $composite<T, U>;
+
+            fn div(self, other: $scalar) -> Self::Output {
+                $composite(self.0 / $to_f64(other), self.1)
+            }
+        }
+
+        impl<T, U> $crate::__core::ops::DivAssign<$scalar> for $composite<T, U>
+            where T: $crate::__core::ops::DivAssign<f64>
+        {
+            fn div_assign(&mut self, other: $scalar) {
+                self.0 /= $to_f64(other);
+            }
+        }
+
+        impl<T, U> $crate::__core::ops::Div<$composite<T, U>> for $scalar
+            where T: $crate::composite::Unit
+        {
+            type Output = $crate::composite::Per<$composite<T, U>>;
+
+            fn div(self, other: $composite<T, U>) -> Self::Output {
+                $crate::composite::Div($crate::composite::One($to_f64(self) / $crate::composite::Unit::inner(&other)),
+                                       $crate::__core::marker::PhantomData)
             }
         }
     }
 }
 
-macro_rules! impl_composite_base {
+//impl_unit gives a unit its `Unit` impl on its own, for units such as the gauge scales that stay out of composites
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_unit {
     ($type_a:tt) => {
-        impl Unit for $type_a {
+        $crate::impl_unit!(@generic [] $type_a);
+    };
+    (@generic [$($gen:tt)*] $type_a:ty) => {
+        impl<$($gen)*> $crate::composite::Unit for $type_a {
+            const ZERO: Self = $crate::composite::from_raw_value(0.0);
+            const ONE: Self = $crate::composite::from_raw_value(1.0);
+
             fn new(val: f64) -> Self {
-                $type_a(val)
+                $crate::composite::from_raw_value(val)
             }
             fn inner(&self) -> f64 {
                 self.0
             }
         }
+    }
+}
 
-        impl<T, U> core::ops::Mul<Mul<T, U>> for $type_a
-            where T: Unit,
-        {
-            type Output = Mul<$type_a, Mul<T, U>>;
+//impl_composite_base makes a unit an operand of `*` and `/`. A single blanket impl per operator covers every other
+//unit, composite and reference, multiplying into a `Mul` composite and dividing into either a `Div` composite or,
+//for units of the same dimension, a plain `f64`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_base {
+    ($type_a:tt) => {
+        $crate::impl_composite_base!(@generic [] $type_a);
+    };
+    (@generic [$($gen:tt)*] $type_a:ty) => {
+        $crate::impl_composite_base!(@operand [$($gen)*] $type_a);
 
-            fn mul(self, other: Mul<T, U>) -> Self::Output {
-                Mul($type_a(other.0.inner() * self.0), PhantomData)
+        impl<$($gen)*> $type_a {
+            /// Returns the reciprocal of `self`.
+            #[inline(always)]
+            pub fn recip(self) -> $crate::composite::Per<$type_a> {
+                $crate::composite::Div($crate::composite::One(1.0 / self.0), $crate::__core::marker::PhantomData)
             }
         }
-        impl<T, U> core::ops::Mul<$type_a> for Mul<T, U>
-            where T: Unit,
+
+        impl<U, $($gen)*> $crate::composite::Invert<U> for $type_a
+            where U: $crate::composite::Unit
         {
-            type Output = Mul<T, Mul<$type_a, U>>;
+            type Output = $crate::composite::Div<U, $type_a>;
+        }
 
-            fn mul(self, other: $type_a) -> Self::Output {
-                Mul(T::new(other.0 * self.0.inner()), PhantomData)
+        $crate::impl_for_scalars!(impl_recip_scalar!([$($gen)*] $type_a));
+    };
+    //`One` takes only this part, as it is the numerator of reciprocals rather than a unit with one
+    (@operand $type_a:tt) => {
+        $crate::impl_composite_base!(@operand [] $type_a);
+    };
+    (@operand [$($gen:tt)*] $type_a:ty) => {
+        $crate::impl_unit!(@generic [$($gen)*] $type_a);
+
+        impl<$($gen)*> $crate::composite::Operand for $type_a {
+            type Unit = $type_a;
+
+            #[inline(always)]
+            fn unit(self) -> $type_a {
+                self
+            }
+        }
+        impl<'a, $($gen)*> $crate::composite::Operand for &'a $type_a {
+            type Unit = $type_a;
+
+            #[inline(always)]
+            fn unit(self) -> $type_a {
+                *self
             }
         }
 
-        impl<T, U> core::ops::Mul<Div<T, U>> for $type_a
-            where T: Unit,
+        impl<V, $($gen)*> $crate::__core::ops::Mul<V> for $type_a
+            where V: $crate::composite::MulRhs<$type_a>
         {
-            type Output = Mul<$type_a, Div<T, U>>;
+            type Output = V::Output;
 
-            fn mul(self, other: Div<T, U>) -> Self::Output {
-                Mul($type_a(other.0.inner() * self.0), PhantomData)
+            fn mul(self, other: V) -> Self::Output {
+                $crate::composite::MulRhs::mul_rhs(other, self)
             }
         }
-        impl<T, U> core::ops::Mul<$type_a> for Div<T, U>
-            where T: Unit,
+        impl<V, $($gen)*> $crate::__core::ops::Div<V> for $type_a
+            where V: $crate::composite::DivRhs<$type_a>
         {
-            type Output = Mul<T, Div<$type_a, U>>;
+            type Output = V::Output;
 
-            fn mul(self, other: $type_a) -> Self::Output {
-                Mul(T::new(other.0 * self.0.inner()), PhantomData)
+            fn div(self, other: V) -> Self::Output {
+                $crate::composite::DivRhs::div_rhs(other, self)
             }
         }
 
-        impl<T, U> core::ops::Div<Mul<T, U>> for $type_a
-            where T: Unit
+        impl<T, U, $($gen)*> $crate::__core::ops::Mul<$type_a> for $crate::composite::Mul<T, U>
+            where T: $crate::composite::Unit,
         {
-            type Output = Div<$type_a, Mul<T, U>>;
+            type Output = $crate::composite::Mul<T, $crate::composite::Mul<$type_a, U>>;
 
-            fn div(self, other: Mul<T, U>) -> Self::Output {
-                Div($type_a(self.0 / other.0.inner()), PhantomData)
+            fn mul(self, other: $type_a) -> Self::Output {
+                $crate::composite::Mul(T::new(other.0 * self.0.inner()), $crate::__core::marker::PhantomData)
             }
         }
-        impl<T, U> core::ops::Div<$type_a> for Mul<T, U>
-            where T: Unit
+        impl<T, U, $($gen)*> $crate::__core::ops::Mul<$type_a> for $crate::composite::Div<T, U>
+            where T: $crate::composite::Unit,
         {
-            type Output = Mul<T, Div<$type_a, U>>;
+            type Output = $crate::composite::Mul<T, $crate::composite::Div<$type_a, U>>;
 
-            fn div(self, other: $type_a) -> Self::Output {
-                Mul(T::new(self.0.inner() / other.0), PhantomData)
+            fn mul(self, other: $type_a) -> Self::Output {
+                $crate::composite::Mul(T::new(other.0 * self.0.inner()), $crate::__core::marker::PhantomData)
             }
         }
-
-        impl<T, U> core::ops::Div<Div<T, U>> for $type_a
-            where T: Unit
+        impl<T, U, $($gen)*> $crate::__core::ops::Div<$type_a> for $crate::composite::Mul<T, U>
+            where $crate::composite::Mul<T, U>:
+                      $crate::composite::Divide<$type_a, $crate::composite::Mul<T, $crate::composite::Div<$type_a, U>>>
         {
-            type Output = Div<$type_a, Div<T, U>>;
+            type Output = <$crate::composite::Mul<T, U> as $crate::composite::Divide<
+                $type_a,
+                $crate::composite::Mul<T, $crate::composite::Div<$type_a, U>>
+            >>::Output;
 
-            fn div(self, other: Div<T, U>) -> Self::Output {
-                Div($type_a(self.0 / other.0.inner()), PhantomData)
+            fn div(self, other: $type_a) -> Self::Output {
+                $crate::composite::Divide::divide(self, other)
             }
         }
-        impl<T, U> core::ops::Div<$type_a> for Div<T, U>
-            where T: Unit
+        impl<T, U, $($gen)*> $crate::__core::ops::Div<$type_a> for $crate::composite::Div<T, U>
+            where $crate::composite::Div<T, U>:
+                      $crate::composite::Divide<$type_a, $crate::composite::Div<T, $crate::composite::Mul<$type_a, U>>>
         {
-            type Output = Div<T, Mul<$type_a, U>>;
+            type Output = <$crate::composite::Div<T, U> as $crate::composite::Divide<
+                $type_a,
+                $crate::composite::Div<T, $crate::composite::Mul<$type_a, U>>
+            >>::Output;
 
             fn div(self, other: $type_a) -> Self::Output {
-                Div(T::new(self.0.inner() / other.0), PhantomData)
+                $crate::composite::Divide::divide(self, other)
             }
         }
+    }
+}
 
-        impl core::ops::Mul for $type_a
-        {
-            type Output = Mul<$type_a, $type_a>;
+//impl_recip_scalar divides a scalar by a unit, giving its reciprocal
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_recip_scalar {
+    ([$($gen:tt)*] $type_a:ty, $scalar:ty, $to_f64:expr) => {
+        impl<$($gen)*> $crate::__core::ops::Div<$type_a> for $scalar {
+            type Output = $crate::composite::Per<$type_a>;
 
-            fn mul(self, other: $type_a) -> Self::Output {
-                Mul($type_a(self.0 * other.0), PhantomData)
+            fn div(self, other: $type_a) -> Self::Output {
+                $crate::composite::Div($crate::composite::One($to_f64(self) / other.0), $crate::__core::marker::PhantomData)
             }
         }
     }
 }
 
-macro_rules! impl_composite {
-    ($type_a:tt, $type_b:tt) => {
-        impl core::ops::Mul<$type_b> for $type_a
+//impl_base_dimension gives a base unit its dimension: one of the built-in vectors of `dim`, a declared dimension
+//identified by a path unique to it, or that of the composite of base units it is defined by
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_base_dimension {
+    ($base:ty = $dim:ident) => {
+        impl $crate::dim::Dimensioned for $base {
+            type Dim = $crate::dim::$dim;
+        }
+    };
+    ($base:ty, $path:expr) => {
+        impl $crate::dim::Dimensioned for $base {
+            type Dim = $crate::dim::Declared<$crate::__dimension_id!($path)>;
+        }
+    };
+    ($base:ty => $composite:ty) => {
+        impl $crate::dim::Dimensioned for $base {
+            type Dim = <$composite as $crate::dim::Dimensioned>::Dim;
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_math {
+    ($impl_type:tt) => {
+        $crate::impl_math!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                           $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<$($gen)*> $impl_type {
+            /// Returns the absolute value of `self`.
+            #[inline(always)]
+            pub fn abs(self) -> $impl_type {
+                $crate::composite::Unit::new(self.0.abs())
+            }
+
+            /// Returns the smaller of `self` and `other`, in the unit of `self`.
+            #[inline(always)]
+            pub fn min<V>(self, other: V) -> $impl_type
+                where V: $rhs
+            {
+                let other: Self = $conv(other);
+                $crate::composite::Unit::new(self.0.min(other.0))
+            }
+
+            /// Returns the larger of `self` and `other`, in the unit of `self`.
+            #[inline(always)]
+            pub fn max<V>(self, other: V) -> $impl_type
+                where V: $rhs
+            {
+                let other: Self = $conv(other);
+                $crate::composite::Unit::new(self.0.max(other.0))
+            }
+
+            /// Restricts `self` to the interval `[min, max]`, in the unit of `self`.
+            ///
+            /// Panics if `min > max` or either bound is NaN.
+            #[inline(always)]
+            pub fn clamp<V, W>(self, min: V, max: W) -> $impl_type
+                where V: $rhs,
+                      W: $rhs
+            {
+                let min: Self = $conv(min);
+                let max: Self = $conv(max);
+                $crate::composite::Unit::new(self.0.clamp(min.0, max.0))
+            }
+
+            /// Returns `true` if `self` is neither infinite nor NaN.
+            #[inline(always)]
+            pub fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+
+            /// Returns `true` if `self` is NaN.
+            #[inline(always)]
+            pub fn is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+        }
+
+        $crate::__if_math! {
+            impl<$($gen)*> $impl_type {
+                /// Returns the largest whole number of this unit less than or equal to `self`.
+                #[inline(always)]
+                pub fn floor(self) -> $impl_type {
+                    $crate::composite::Unit::new($crate::math::floor(self.0))
+                }
+
+                /// Returns the smallest whole number of this unit greater than or equal to `self`.
+                #[inline(always)]
+                pub fn ceil(self) -> $impl_type {
+                    $crate::composite::Unit::new($crate::math::ceil(self.0))
+                }
+
+                /// Returns the nearest whole number of this unit, rounding half-way cases away from zero.
+                #[inline(always)]
+                pub fn round(self) -> $impl_type {
+                    $crate::composite::Unit::new($crate::math::round(self.0))
+                }
+
+                /// Returns the whole number part of `self`, in this unit.
+                #[inline(always)]
+                pub fn trunc(self) -> $impl_type {
+                    $crate::composite::Unit::new($crate::math::trunc(self.0))
+                }
+
+                /// Rounds `self` to the nearest multiple of `step`, which may be given in another unit.
+                #[inline(always)]
+                pub fn round_to<V>(self, step: V) -> $impl_type
+                    where V: $rhs
+                {
+                    let step: Self = $conv(step);
+                    $crate::composite::Unit::new($crate::math::round(self.0 / step.0) * step.0)
+                }
+
+                /// Returns the hypotenuse of a right triangle whose legs are `self` and `other`.
+                #[inline(always)]
+                pub fn hypot<V>(self, other: V) -> $impl_type
+                    where V: $rhs
+                {
+                    let other: Self = $conv(other);
+                    $crate::composite::Unit::new($crate::math::hypot(self.0, other.0))
+                }
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_math {
+    ($composite:ident) => {
+        impl<T, U> $composite<T, U>
+            where T: $crate::composite::Unit
         {
-            type Output = Mul<$type_a, $type_b>;
+            /// Returns the absolute value of `self`.
+            #[inline(always)]
+            pub fn abs(self) -> $composite<T, U> {
+                $composite(T::new(self.inner().abs()), $crate::__core::marker::PhantomData)
+            }
+
+            /// Returns the smaller of `self` and `other`, which may be in any units of the same dimension.
+            #[inline(always)]
+            pub fn min<V>(self, other: V) -> $composite<T, U>
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                $composite(T::new(self.inner().min(other)), $crate::__core::marker::PhantomData)
+            }
+
+            /// Returns the larger of `self` and `other`, which may be in any units of the same dimension.
+            #[inline(always)]
+            pub fn max<V>(self, other: V) -> $composite<T, U>
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                $composite(T::new(self.inner().max(other)), $crate::__core::marker::PhantomData)
+            }
+
+            /// Restricts `self` to the interval `[min, max]`, whose bounds may be in any units of the same dimension.
+            ///
+            /// Panics if `min > max` or either bound is NaN.
+            #[inline(always)]
+            pub fn clamp<V, W>(self, min: V, max: W) -> $composite<T, U>
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>,
+                      W: $crate::composite::Quantity,
+                      W::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let min = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&min));
+                let max = $crate::composite::convert_raw::<W, Self>($crate::composite::Unit::inner(&max));
+                $composite(T::new(self.inner().clamp(min, max)), $crate::__core::marker::PhantomData)
+            }
+
+            /// Returns `true` if `self` is neither infinite nor NaN.
+            #[inline(always)]
+            pub fn is_finite(&self) -> bool {
+                self.inner().is_finite()
+            }
+
+            /// Returns `true` if `self` is NaN.
+            #[inline(always)]
+            pub fn is_nan(&self) -> bool {
+                self.inner().is_nan()
+            }
+        }
+
+        $crate::__if_math! {
+            impl<T, U> $composite<T, U>
+                where T: $crate::composite::Unit
+            {
+                /// Returns the largest whole number of this unit less than or equal to `self`.
+                #[inline(always)]
+                pub fn floor(self) -> $composite<T, U> {
+                    $composite(T::new($crate::math::floor(self.inner())), $crate::__core::marker::PhantomData)
+                }
+
+                /// Returns the smallest whole number of this unit greater than or equal to `self`.
+                #[inline(always)]
+                pub fn ceil(self) -> $composite<T, U> {
+                    $composite(T::new($crate::math::ceil(self.inner())), $crate::__core::marker::PhantomData)
+                }
+
+                /// Returns the nearest whole number of this unit, rounding half-way cases away from zero.
+                #[inline(always)]
+                pub fn round(self) -> $composite<T, U> {
+                    $composite(T::new($crate::math::round(self.inner())), $crate::__core::marker::PhantomData)
+                }
+
+                /// Returns the whole number part of `self`, in this unit.
+                #[inline(always)]
+                pub fn trunc(self) -> $composite<T, U> {
+                    $composite(T::new($crate::math::trunc(self.inner())), $crate::__core::marker::PhantomData)
+                }
+
+                /// Rounds `self` to the nearest multiple of `step`, which may be in any units of the same dimension.
+                #[inline(always)]
+                pub fn round_to<V>(self, step: V) -> $composite<T, U>
+                    where Self: $crate::composite::Quantity,
+                          V: $crate::composite::Quantity,
+                          V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                           Result = $crate::composite::Same>
+                {
+                    let step = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&step));
+                    $composite(T::new($crate::math::round(self.inner() / step) * step),
+                               $crate::__core::marker::PhantomData)
+                }
+
+                /// Returns the hypotenuse of a right triangle whose legs are `self` and `other`, which may be in any
+                /// units of the same dimension.
+                #[inline(always)]
+                pub fn hypot<V>(self, other: V) -> $composite<T, U>
+                    where Self: $crate::composite::Quantity,
+                          V: $crate::composite::Quantity,
+                          V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                           Result = $crate::composite::Same>
+                {
+                    let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                    $composite(T::new($crate::math::hypot(self.inner(), other)), $crate::__core::marker::PhantomData)
+                }
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_approx_eq {
+    ($impl_type:tt) => {
+        $crate::impl_approx_eq!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                                $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        impl<$($gen)*> $impl_type {
+            /// Returns `true` if `self` and `other` differ by no more than `epsilon`.
+            ///
+            /// Both `other` and `epsilon` may be given in any unit that converts into this one. The tolerance is
+            /// converted as a difference, so e.g. a tolerance of `Kelvin(1.0)` means one degree Celsius.
+            #[inline(always)]
+            pub fn abs_diff_eq<V, E>(self, other: V, epsilon: E) -> bool
+                where V: $rhs,
+                      E: $crate::composite::Unit + $rhs
+            {
+                let other: Self = $conv(other);
+                let zero: Self = $conv(E::ZERO);
+                let epsilon: Self = $conv(epsilon);
+                $crate::approx_eq::abs_diff_eq(self.0, other.0, epsilon.0 - zero.0)
+            }
+
+            /// Returns `true` if `self` and `other` differ by no more than `max_relative` times the larger of their
+            /// magnitudes, measured in the unit of `self`.
+            #[inline(always)]
+            pub fn relative_eq<V>(self, other: V, max_relative: f64) -> bool
+                where V: $rhs
+            {
+                let other: Self = $conv(other);
+                $crate::approx_eq::relative_eq(self.0, other.0, max_relative)
+            }
+
+            /// Returns `true` if `self` and `other` are no more than `max_ulps` representable values apart, once
+            /// `other` is converted into the unit of `self`.
+            #[inline(always)]
+            pub fn ulps_eq<V>(self, other: V, max_ulps: u32) -> bool
+                where V: $rhs
+            {
+                let other: Self = $conv(other);
+                $crate::approx_eq::ulps_eq(self.0, other.0, max_ulps)
+            }
+        }
+
+        $crate::__if_approx! {
+            impl<$($gen)*> $crate::__approx::AbsDiffEq for $impl_type {
+                type Epsilon = f64;
+
+                fn default_epsilon() -> f64 {
+                    f64::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.0, other.0, epsilon)
+                }
+            }
+        }
+        $crate::__if_approx! {
+            impl<$($gen)*> $crate::__approx::RelativeEq for $impl_type {
+                fn default_max_relative() -> f64 {
+                    f64::EPSILON
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.0, other.0, epsilon) ||
+                    $crate::approx_eq::relative_eq(self.0, other.0, max_relative)
+                }
+            }
+        }
+        $crate::__if_approx! {
+            impl<$($gen)*> $crate::__approx::UlpsEq for $impl_type {
+                fn default_max_ulps() -> u32 {
+                    4
+                }
 
-            fn mul(self, other: $type_b) -> Self::Output {
-                Mul($type_a(self.0 * other.0), PhantomData)
+                fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.0, other.0, epsilon) ||
+                    $crate::approx_eq::ulps_eq(self.0, other.0, max_ulps)
+                }
             }
         }
-        impl core::ops::Mul<$type_a> for $type_b
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_approx_eq {
+    ($composite:ident) => {
+        impl<T, U> $composite<T, U>
+            where T: $crate::composite::Unit
         {
-            type Output = Mul<$type_b, $type_a>;
+            /// Returns `true` if `self` and `other` differ by no more than `epsilon`.
+            ///
+            /// Both `other` and `epsilon` may be given in any units of the same dimension, such as a tolerance in
+            /// `km/h` for a speed in `m/s`. The tolerance is converted as a difference.
+            #[inline(always)]
+            pub fn abs_diff_eq<V, E>(self, other: V, epsilon: E) -> bool
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>,
+                      E: $crate::composite::Quantity,
+                      E::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                let epsilon = $crate::composite::convert_raw::<E, Self>($crate::composite::Unit::inner(&epsilon)) -
+                              $crate::composite::convert_raw::<E, Self>(0.0);
+                $crate::approx_eq::abs_diff_eq(self.inner(), other, epsilon)
+            }
 
-            fn mul(self, other: $type_a) -> Self::Output {
-                Mul($type_b(self.0 * other.0), PhantomData)
+            /// Returns `true` if `self` and `other` differ by no more than `max_relative` times the larger of their
+            /// magnitudes, measured in the unit of `self`. `other` may be in any units of the same dimension.
+            #[inline(always)]
+            pub fn relative_eq<V>(self, other: V, max_relative: f64) -> bool
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                $crate::approx_eq::relative_eq(self.inner(), other, max_relative)
+            }
+
+            /// Returns `true` if `self` and `other` are no more than `max_ulps` representable values apart, once
+            /// `other`, which may be in any units of the same dimension, is converted into the unit of `self`.
+            #[inline(always)]
+            pub fn ulps_eq<V>(self, other: V, max_ulps: u32) -> bool
+                where Self: $crate::composite::Quantity,
+                      V: $crate::composite::Quantity,
+                      V::Base: $crate::composite::Compare<<Self as $crate::composite::Quantity>::Base,
+                                                       Result = $crate::composite::Same>
+            {
+                let other = $crate::composite::convert_raw::<V, Self>($crate::composite::Unit::inner(&other));
+                $crate::approx_eq::ulps_eq(self.inner(), other, max_ulps)
+            }
+        }
+
+        $crate::__if_approx! {
+            impl<T, U> $crate::__approx::AbsDiffEq for $composite<T, U>
+                where T: $crate::composite::Unit + Copy, U: Copy, $composite<T, U>: PartialEq
+            {
+                type Epsilon = f64;
+
+                fn default_epsilon() -> f64 {
+                    f64::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.inner(), other.inner(), epsilon)
+                }
             }
         }
-        impl core::ops::Div<$type_b> for $type_a
+        $crate::__if_approx! {
+            impl<T, U> $crate::__approx::RelativeEq for $composite<T, U>
+                where T: $crate::composite::Unit + Copy, U: Copy, $composite<T, U>: PartialEq
+            {
+                fn default_max_relative() -> f64 {
+                    f64::EPSILON
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.inner(), other.inner(), epsilon) ||
+                    $crate::approx_eq::relative_eq(self.inner(), other.inner(), max_relative)
+                }
+            }
+        }
+        $crate::__if_approx! {
+            impl<T, U> $crate::__approx::UlpsEq for $composite<T, U>
+                where T: $crate::composite::Unit + Copy, U: Copy, $composite<T, U>: PartialEq
+            {
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+                    $crate::approx_eq::abs_diff_eq(self.inner(), other.inner(), epsilon) ||
+                    $crate::approx_eq::ulps_eq(self.inner(), other.inner(), max_ulps)
+                }
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_quantity {
+    ($impl_type:tt => $base:ty, $scale:expr) => {
+        $crate::impl_quantity!($impl_type => $base, $scale, 0.0);
+    };
+    ($impl_type:tt => $base:ty, $scale:expr, $offset:expr) => {
+        $crate::impl_quantity!(@generic [] $impl_type => $base, $scale, $offset);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty => $base:ty, $scale:expr, $offset:expr) => {
+        unsafe impl<$($gen)*> $crate::composite::F64Repr for $impl_type {}
+
+        impl<$($gen)*> $crate::composite::Quantity for $impl_type {
+            type Base = $base;
+            const SCALE: f64 = $scale;
+            const OFFSET: f64 = $offset;
+        }
+
+        impl<$($gen)*> $impl_type {
+            /// Converts `other` into this unit, in `const` contexts.
+            #[inline(always)]
+            pub const fn from_const<T>(other: T) -> $impl_type
+                where T: $crate::composite::Quantity<Base = $base>
+            {
+                $crate::composite::convert_const(other)
+            }
+
+            /// Adds `other`, which may be given in another unit, in `const` contexts.
+            #[inline(always)]
+            pub const fn add_const<T>(self, other: T) -> $impl_type
+                where T: $crate::composite::Quantity<Base = $base>
+            {
+                $crate::composite::from_raw_value(self.0 + Self::from_const(other).0)
+            }
+
+            /// Subtracts `other`, which may be given in another unit, in `const` contexts.
+            #[inline(always)]
+            pub const fn sub_const<T>(self, other: T) -> $impl_type
+                where T: $crate::composite::Quantity<Base = $base>
+            {
+                $crate::composite::from_raw_value(self.0 - Self::from_const(other).0)
+            }
+
+            /// Multiplies `self` by `factor`, in `const` contexts.
+            #[inline(always)]
+            pub const fn scale_const(self, factor: f64) -> $impl_type {
+                $crate::composite::from_raw_value(self.0 * factor)
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_composite_quantity {
+    ($composite:ident, $op:tt) => {
+        unsafe impl<T, U> $crate::composite::F64Repr for $composite<T, U>
+            where T: $crate::composite::F64Repr,
+                  U: Copy
+        {}
+
+        impl<T, U> $crate::composite::Quantity for $composite<T, U>
+            where T: $crate::composite::Quantity,
+                  U: $crate::composite::Quantity
         {
-            type Output = Div<$type_a, $type_b>;
+            type Base = $composite<T::Base, U::Base>;
+            const SCALE: f64 = T::SCALE $op U::SCALE;
+        }
+
+        impl<T, U> $composite<T, U>
+            where T: $crate::composite::F64Repr,
+                  U: Copy
+        {
+            /// Creates a value of this composite from a number in the unit of `T`, in `const` contexts.
+            #[inline(always)]
+            pub const fn from_value(value: f64) -> $composite<T, U> {
+                $composite($crate::composite::from_raw_value(value), $crate::__core::marker::PhantomData)
+            }
+
+            /// Returns the number stored in `self`, in the unit of `T`, in `const` contexts.
+            #[inline(always)]
+            pub const fn value(self) -> f64 {
+                $crate::composite::raw_value(self)
+            }
 
-            fn div(self, other: $type_b) -> Self::Output {
-                Div($type_a(self.0 / other.0), PhantomData)
+            /// Multiplies `self` by `factor`, in `const` contexts.
+            #[inline(always)]
+            pub const fn scale_const(self, factor: f64) -> $composite<T, U> {
+                $composite::from_value($crate::composite::raw_value(self) * factor)
             }
         }
-        impl core::ops::Div<$type_a> for $type_b
+
+        impl<T, U> $composite<T, U>
+            where T: $crate::composite::Quantity,
+                  U: $crate::composite::Quantity
         {
-            type Output = Div<$type_b, $type_a>;
+            /// Converts `other` into this composite, in `const` contexts.
+            #[inline(always)]
+            pub const fn from_const<V>(other: V) -> $composite<T, U>
+                where V: $crate::composite::Quantity<Base = <$composite<T, U> as $crate::composite::Quantity>::Base>
+            {
+                $crate::composite::convert_const(other)
+            }
 
-            fn div(self, other: $type_a) -> Self::Output {
-                Div($type_b(self.0 / other.0), PhantomData)
+            /// Adds `other`, which may be given in other units, in `const` contexts.
+            #[inline(always)]
+            pub const fn add_const<V>(self, other: V) -> $composite<T, U>
+                where V: $crate::composite::Quantity<Base = <$composite<T, U> as $crate::composite::Quantity>::Base>
+            {
+                $composite::from_value($crate::composite::raw_value(self) + $crate::composite::raw_value($composite::<T, U>::from_const(other)))
+            }
+
+            /// Subtracts `other`, which may be given in other units, in `const` contexts.
+            #[inline(always)]
+            pub const fn sub_const<V>(self, other: V) -> $composite<T, U>
+                where V: $crate::composite::Quantity<Base = <$composite<T, U> as $crate::composite::Quantity>::Base>
+            {
+                $composite::from_value($crate::composite::raw_value(self) - $crate::composite::raw_value($composite::<T, U>::from_const(other)))
             }
         }
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_basic_ops {
     ($impl_type:tt) => {
-        impl_add!($impl_type);
-        impl_addassign!($impl_type);
-        impl_sub!($impl_type);
-        impl_subassign!($impl_type);
+        $crate::impl_basic_ops!(@generic [] $impl_type, $crate::__core::convert::Into<$impl_type>,
+                                $crate::__core::convert::Into::into);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty, $rhs:path, $conv:path) => {
+        $crate::impl_add!(@generic [$($gen)*] $impl_type, $rhs, $conv);
+        $crate::impl_addassign!(@generic [$($gen)*] $impl_type, $rhs, $conv);
+        $crate::impl_sub!(@generic [$($gen)*] $impl_type, $rhs, $conv);
+        $crate::impl_subassign!(@generic [$($gen)*] $impl_type, $rhs, $conv);
+        $crate::impl_neg!(@generic [$($gen)*] $impl_type);
+        $crate::impl_ref_ops!(@generic [$($gen)*] $impl_type);
+        $crate::impl_consts!(@generic [$($gen)*] $impl_type);
+        $crate::impl_sum!(@generic [$($gen)*] $impl_type, $rhs, $conv);
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_full_unit {
     ($impl_type:tt) => {
-        impl_basic_ops!($impl_type);
-        impl_div_same!($impl_type);
-        impl_scalar_ops!($impl_type);
-        impl_partial_ord!($impl_type);
+        $crate::impl_basic_ops!($impl_type);
+        $crate::impl_rem!($impl_type);
+        $crate::impl_div_same!($impl_type);
+        $crate::impl_scalar_ops!($impl_type);
+        $crate::impl_partial_ord!($impl_type);
+        $crate::impl_math!($impl_type);
+        $crate::impl_approx_eq!($impl_type);
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_scalar_ops {
     ($impl_type:tt) => {
-        impl_mul_scalar!($impl_type);
-        impl_mulassign_scalar!($impl_type);
-        impl_div_scalar!($impl_type);
-        impl_divassign_scalar!($impl_type);
+        $crate::impl_scalar_ops!(@generic [] $impl_type);
+    };
+    (@generic [$($gen:tt)*] $impl_type:ty) => {
+        impl<$($gen)*> $crate::composite::Scalable for $impl_type {}
+
+        $crate::impl_mul_scalar!(@generic [$($gen)*] $impl_type);
+        $crate::impl_mulassign_scalar!(@generic [$($gen)*] $impl_type);
+        $crate::impl_divassign_scalar!(@generic [$($gen)*] $impl_type);
     }
 }
 
-macro_rules! impl_from {
-    ($from_type:tt => $impl_type:tt, $conversion:expr) => {
-        impl From<$from_type> for $impl_type {
-            fn from(f: $from_type) -> Self {
-                $impl_type($conversion(f.0))
+//impl_from_quantity converts both ways between two units of the same dimension, through their `Quantity` scales
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_from_quantity {
+    ($impl_type:ty, $other:ty) => {
+        impl From<$other> for $impl_type {
+            fn from(f: $other) -> Self {
+                $crate::composite::convert_const(f)
             }
         }
-        impl<'a> From<&'a $from_type> for $impl_type {
-            fn from(f: &'a $from_type) -> Self {
-                $impl_type($conversion(f.0))
+        impl From<$impl_type> for $other {
+            fn from(f: $impl_type) -> Self {
+                $crate::composite::convert_const(f)
+            }
+        }
+        impl<'a> From<&'a $other> for $impl_type {
+            fn from(f: &'a $other) -> Self {
+                $crate::composite::convert_const(*f)
+            }
+        }
+        impl<'a> From<&'a $impl_type> for $other {
+            fn from(f: &'a $impl_type) -> Self {
+                $crate::composite::convert_const(*f)
             }
         }
     }
 }
 
-//impl_from for constant factor conversions
-macro_rules! impl_from_cf {
-    ($from_type:tt <===> $conversion:tt $impl_type:tt) => {
-        impl From<$from_type> for $impl_type {
-            fn from(f: $from_type) -> Self {
-                $impl_type(f.0 * $conversion)
+//impl_from_composite converts a named unit to and from every `Mul` and `Div` composite of the same dimension, such
+//as a force to and from `kg*m/s^2`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_from_composite {
+    ($impl_type:ident) => {
+        $crate::impl_from_composite!($impl_type, Mul);
+        $crate::impl_from_composite!($impl_type, Div);
+    };
+    ($impl_type:ident, $composite:ident) => {
+        impl<T, U> From<$crate::composite::$composite<T, U>> for $impl_type
+            where $crate::composite::$composite<T, U>: $crate::composite::Quantity,
+                  <$crate::composite::$composite<T, U> as $crate::composite::Quantity>::Base:
+                      $crate::composite::Compare<<$impl_type as $crate::composite::Quantity>::Base,
+                                                 Result = $crate::composite::Same>
+        {
+            fn from(f: $crate::composite::$composite<T, U>) -> Self {
+                $impl_type($crate::composite::convert_raw::<$crate::composite::$composite<T, U>, $impl_type>(
+                    $crate::composite::Unit::inner(&f)))
             }
         }
-        impl From<$impl_type> for $from_type {
+        impl<T, U> From<$impl_type> for $crate::composite::$composite<T, U>
+            where $crate::composite::$composite<T, U>: $crate::composite::Quantity,
+                  <$crate::composite::$composite<T, U> as $crate::composite::Quantity>::Base:
+                      $crate::composite::Compare<<$impl_type as $crate::composite::Quantity>::Base,
+                                                 Result = $crate::composite::Same>
+        {
             fn from(f: $impl_type) -> Self {
-                $from_type(f.0 / $conversion)
+                $crate::composite::Unit::new($crate::composite::convert_raw::<$impl_type, Self>(f.0))
             }
         }
-    }
+    };
 }
 
 //impl_through for conversion by intermediary
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_through {
     ($from_type:tt => $through_type:tt => $impl_type:tt) => {
         impl From<$from_type> for $impl_type {
@@ -424,39 +1533,41 @@ macro_rules! impl_through {
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_unit_debug {
     ($impl_type:tt => $unitstr:expr) => {
-        impl UnitName for $impl_type {
+        impl $crate::composite::UnitName for $impl_type {
             fn get_unit(&self) -> &'static str {
                 return $unitstr;
             }
             fn get_unit_static() -> &'static str {
                 return $unitstr;
             }
-            fn write_unit_static(f: &mut fmt::Formatter) -> fmt::Result {
+            fn write_unit_static(f: &mut $crate::__core::fmt::Formatter) -> $crate::__core::fmt::Result {
                 write!(f, $unitstr)
             }
         }
-        impl fmt::Debug for $impl_type {
-            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        impl $crate::__core::fmt::Debug for $impl_type {
+            fn fmt(&self, f: &mut $crate::__core::fmt::Formatter) -> $crate::__core::fmt::Result {
                 write!(f, "{}{}", self.0, $unitstr)
             }
         }
     };
     ($impl_type:tt => $unitstr:expr, $unitstr_plural:expr) => {
-        impl UnitName for $impl_type {
+        impl $crate::composite::UnitName for $impl_type {
             fn get_unit(&self) -> &'static str {
                 return $unitstr;
             }
             fn get_unit_static() -> &'static str {
                 return $unitstr;
             }
-            fn write_unit_static(f: &mut fmt::Formatter) -> fmt::Result {
+            fn write_unit_static(f: &mut $crate::__core::fmt::Formatter) -> $crate::__core::fmt::Result {
                 write!(f, $unitstr)
             }
         }
-        impl fmt::Debug for $impl_type {
-            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        impl $crate::__core::fmt::Debug for $impl_type {
+            fn fmt(&self, f: &mut $crate::__core::fmt::Formatter) -> $crate::__core::fmt::Result {
                 if self.0 == 1.0 {
                     write!(f, "{}{}", self.0, $unitstr)
                 } else {
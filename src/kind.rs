@@ -0,0 +1,284 @@
+//! Provides `Kinded`, which tags a quantity with its kind so that quantities sharing a dimension don't mix.
+//!
+//! Torque and energy are both a force times a length, and frequency and radioactivity are both per second. Wrapping
+//! them in `Kinded` keeps a torque from being added to an energy, while quantities of the same kind still add up
+//! whatever their units, whether a named unit such as `Joule` or a composite such as `Mul<Newton, Meter>`. Moving
+//! between kinds is explicit, either through a relation like `Kinded::work` or with `into_kind` where the caller
+//! vouches for it.
+//!
+//! ```
+//! use metric::angle::{Degree, Radian};
+//! use metric::composite::Mul;
+//! use metric::energy::Joule;
+//! use metric::force::Newton;
+//! use metric::kind::{Energy, Kinded, Torque};
+//! use metric::length::metric::Meter;
+//!
+//! let torque = Kinded::<Torque, _>::new(Mul::<Newton, Meter>::from_value(10.0));
+//! let work: Kinded<Energy, _> = torque.work(Radian(2.0));
+//! assert_eq!(work + Kinded::<Energy, _>::new(Joule(1.0)), Kinded::new(Mul::<Newton, Meter>::from_value(21.0)));
+//! assert_eq!(torque.work(Degree(180.0)), Kinded::<Energy, _>::new(Joule(10.0 * core::f64::consts::PI)));
+//! ```
+//!
+//! ```compile_fail
+//! use metric::composite::Mul;
+//! use metric::force::Newton;
+//! use metric::kind::{Energy, Kinded, Torque};
+//! use metric::length::metric::Meter;
+//!
+//! let torque = Kinded::<Torque, _>::new(Mul::<Newton, Meter>::from_value(10.0));
+//! let energy = Kinded::<Energy, _>::new(Mul::<Newton, Meter>::from_value(10.0));
+//! let _ = torque + energy;
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter;
+use core::marker::PhantomData;
+use core::ops;
+
+use composite::{convert_raw, Compare, Quantity, Same};
+#[cfg(all(feature = "length", feature = "mass", feature = "time", feature = "angle"))]
+use angle::Radian;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use composite::Mul;
+#[cfg(feature = "time")]
+use composite::Per;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use energy::Joule;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use force::Newton;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use length::metric::Meter;
+#[cfg(feature = "time")]
+use time::Second;
+
+/// A kind of quantity, such as `Torque`, distinguishing it from others of the same dimension.
+///
+/// Kinds are zero-sized types, so new ones can be added by implementing this trait.
+pub trait Kind: Copy {
+    /// A unit of the dimension of this kind. Quantities of the kind may be in any unit of the same dimension.
+    type Base: Quantity;
+    /// The name of the kind, written after the value when formatting.
+    const NAME: &'static str;
+}
+
+macro_rules! kinds {
+    ($($(#[$attr:meta])* $name:ident => $base:ty, $kind_name:expr;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl Kind for $name {
+                type Base = $base;
+                const NAME: &'static str = $kind_name;
+            }
+        )*
+    }
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+kinds! {
+    /// A turning force, such as that applied by a wrench, in `N*m`.
+    Torque => Mul<Newton, Meter>, "torque";
+    /// Work or energy, in `J`.
+    Energy => Joule, "energy";
+}
+
+#[cfg(feature = "time")]
+kinds! {
+    /// The number of cycles of a periodic event per unit of time.
+    Frequency => Per<Second>, "frequency";
+    /// The number of nuclear decays per unit of time.
+    Radioactivity => Per<Second>, "radioactivity";
+}
+
+/// A quantity `Q` of the kind `K`.
+///
+/// `Q` may be any unit or composite of the dimension of `K`. Quantities of the same kind can be added, subtracted and
+/// compared in any units of their dimension, with the result in the unit of the left-hand side. They can be scaled
+/// like the quantity they wrap.
+#[derive(Clone, Copy)]
+pub struct Kinded<K, Q>(pub Q, pub PhantomData<K>);
+
+impl<K, Q> Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity,
+          Q::Base: Compare<<K::Base as Quantity>::Base, Result = Same>
+{
+    /// Tags `quantity` as being of the kind `K`.
+    #[inline(always)]
+    pub const fn new(quantity: Q) -> Kinded<K, Q> {
+        Kinded(quantity, PhantomData)
+    }
+
+    /// Returns the quantity, without its kind.
+    #[inline(always)]
+    pub fn quantity(self) -> Q {
+        self.0
+    }
+
+    /// Converts the quantity into another unit of the same dimension, keeping its kind.
+    #[inline(always)]
+    pub fn convert<R>(self) -> Kinded<K, R>
+        where R: Quantity,
+              R::Base: Compare<Q::Base, Result = Same>
+    {
+        Kinded(convert(self.0), PhantomData)
+    }
+
+    /// Tags the quantity as being of another kind of the same dimension.
+    ///
+    /// This is the escape hatch for relations the kinds don't model, so prefer a method such as `work` where one
+    /// exists.
+    #[inline(always)]
+    pub fn into_kind<L>(self) -> Kinded<L, Q>
+        where L: Kind,
+              Q::Base: Compare<<L::Base as Quantity>::Base, Result = Same>
+    {
+        Kinded(self.0, PhantomData)
+    }
+}
+
+#[cfg(all(feature = "length", feature = "mass", feature = "time", feature = "angle"))]
+impl<Q> Kinded<Torque, Q>
+    where Q: Quantity,
+          Q::Base: Compare<Mul<Newton, Meter>, Result = Same>
+{
+    /// Returns the work done by this torque when turning through `angle`, in the unit of the torque.
+    #[inline(always)]
+    pub fn work<A>(self, angle: A) -> Kinded<Energy, Q>
+        where A: Quantity<Base = Radian>
+    {
+        Kinded(Q::new(self.0.inner() * convert_raw::<A, Radian>(angle.inner())), PhantomData)
+    }
+}
+
+//converts between any two quantities of the same dimension, which `convert_const` can't as their bases may differ
+#[inline(always)]
+fn convert<R, Q>(value: R) -> Q
+    where R: Quantity,
+          Q: Quantity
+{
+    Q::new(convert_raw::<R, Q>(value.inner()))
+}
+
+impl<K, Q> fmt::Debug for Kinded<K, Q>
+    where K: Kind,
+          Q: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ({})", self.0, K::NAME)
+    }
+}
+
+impl<K, Q, R> ops::Add<Kinded<K, R>> for Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity + ops::Add<Output = Q>,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    type Output = Kinded<K, Q>;
+
+    fn add(self, other: Kinded<K, R>) -> Self::Output {
+        Kinded(self.0 + convert::<R, Q>(other.0), PhantomData)
+    }
+}
+
+impl<K, Q, R> ops::AddAssign<Kinded<K, R>> for Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity + ops::AddAssign,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    fn add_assign(&mut self, other: Kinded<K, R>) {
+        self.0 += convert::<R, Q>(other.0);
+    }
+}
+
+impl<K, Q, R> ops::Sub<Kinded<K, R>> for Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity + ops::Sub<Output = Q>,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    type Output = Kinded<K, Q>;
+
+    fn sub(self, other: Kinded<K, R>) -> Self::Output {
+        Kinded(self.0 - convert::<R, Q>(other.0), PhantomData)
+    }
+}
+
+impl<K, Q, R> ops::SubAssign<Kinded<K, R>> for Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity + ops::SubAssign,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    fn sub_assign(&mut self, other: Kinded<K, R>) {
+        self.0 -= convert::<R, Q>(other.0);
+    }
+}
+
+impl<K, Q> ops::Neg for Kinded<K, Q>
+    where Q: ops::Neg<Output = Q>
+{
+    type Output = Kinded<K, Q>;
+
+    fn neg(self) -> Self::Output {
+        Kinded(-self.0, PhantomData)
+    }
+}
+
+impl<K, Q> ops::Mul<f64> for Kinded<K, Q>
+    where Q: ops::Mul<f64, Output = Q>
+{
+    type Output = Kinded<K, Q>;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        Kinded(self.0 * factor, PhantomData)
+    }
+}
+
+impl<K, Q> ops::Div<f64> for Kinded<K, Q>
+    where Q: ops::Div<f64, Output = Q>
+{
+    type Output = Kinded<K, Q>;
+
+    fn div(self, factor: f64) -> Self::Output {
+        Kinded(self.0 / factor, PhantomData)
+    }
+}
+
+impl<K, Q> iter::Sum for Kinded<K, Q>
+    where K: Kind,
+          Q: Quantity + ops::Add<Output = Q>,
+          Q::Base: Compare<Q::Base, Result = Same>
+{
+    fn sum<I>(iter: I) -> Self
+        where I: Iterator<Item = Kinded<K, Q>>
+    {
+        iter.fold(Kinded(Q::ZERO, PhantomData), |sum, other| sum + other)
+    }
+}
+
+impl<K, Q, R> PartialEq<Kinded<K, R>> for Kinded<K, Q>
+    where Q: Quantity,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    fn eq(&self, other: &Kinded<K, R>) -> bool {
+        self.0.inner() == convert::<R, Q>(other.0).inner()
+    }
+}
+
+impl<K, Q, R> PartialOrd<Kinded<K, R>> for Kinded<K, Q>
+    where Q: Quantity,
+          R: Quantity,
+          R::Base: Compare<Q::Base, Result = Same>
+{
+    fn partial_cmp(&self, other: &Kinded<K, R>) -> Option<Ordering> {
+        self.0.inner().partial_cmp(&convert::<R, Q>(other.0).inner())
+    }
+}
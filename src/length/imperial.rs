@@ -1,48 +1,58 @@
 //! Defines the imperial length standards as newtypes
 
-use core::{self, fmt};
-
-use composite::UnitName;
+use length::metric::Meter;
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Inch(pub f64);
 pub type Inches = Inch;
 
 impl_full_unit!(Inch);
 impl_unit_debug!(Inch => "in");
+impl_quantity!(Inch => Meter, 0.0254);
+impl_composite_base!(Inch);
 
-impl_from_cf!(Foot <===>    12. Inch);
-impl_from_cf!(Yard <===>    36. Inch);
-impl_from_cf!(Mile <===> 63360. Inch);
+impl_from_quantity!(Foot, Inch);
+impl_from_quantity!(Yard, Inch);
+impl_from_quantity!(Mile, Inch);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Foot(pub f64);
 pub type Feet = Foot;
 
 impl_full_unit!(Foot);
 impl_unit_debug!(Foot => "ft");
+impl_quantity!(Foot => Meter, 0.3048);
+impl_composite_base!(Foot);
 
-impl_from_cf!(Yard <===>    3. Foot);
-impl_from_cf!(Mile <===> 5280. Foot);
+impl_from_quantity!(Yard, Foot);
+impl_from_quantity!(Mile, Foot);
 
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Yard(pub f64);
 pub type Yards = Yard;
 
 impl_full_unit!(Yard);
 impl_unit_debug!(Yard => "yd");
+impl_quantity!(Yard => Meter, 0.9144);
+impl_composite_base!(Yard);
 
-impl_from_cf!(Mile <===> 1760. Yard);
+impl_from_quantity!(Mile, Yard);
 
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Mile(pub f64);
 pub type Miles = Mile;
 
 impl_full_unit!(Mile);
 impl_unit_debug!(Mile => "mi");
+impl_quantity!(Mile => Meter, 1609.344);
+impl_composite_base!(Mile);
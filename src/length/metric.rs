@@ -1,14 +1,12 @@
 //! Defines the metric length standards as newtypes
 
-use core::{self, fmt};
-
 use length::imperial::*;
 use length::astronomical::*;
-
-use composite::UnitName;
+use prefix::{Atto, Giga, Mega, Prefixed};
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Meter(pub f64);
 pub type Meters = Meter;
 pub type Metre = Meter;
@@ -16,22 +14,25 @@ pub type Metres = Meter;
 
 impl_full_unit!(Meter);
 impl_unit_debug!(Meter => "m");
-
-impl_from_cf!(AU        <===>          149597870700.0     Meter     );
-impl_from_cf!(Kilometer <===>                 1_000.0     Meter     );
-impl_from_cf!(Mile      <===>                 1_609.344   Meter     );
-impl_from_cf!(Meter     <===>                   100.0     Centimeter);
-impl_from_cf!(Meter     <===>                 1_000.0     Millimeter);
-impl_from_cf!(Meter     <===>             1_000_000.0     Micrometer);
-impl_from_cf!(Meter     <===>         1_000_000_000.0     Nanometer );
-impl_from_cf!(Meter     <===>     1_000_000_000_000.0     Picometer );
-impl_from_cf!(Meter     <===> 1_000_000_000_000_000.0     Femtometer);
-impl_from_cf!(Meter     <===>                    39.37007 Inch      );
-impl_from_cf!(Meter     <===>          3.280841666667     Foot      );
-impl_from_cf!(Meter     <===>          1.093613888889     Yard      );
+impl_quantity!(Meter => Meter, 1.0);
+impl_composite_base!(Meter);
+
+impl_from_quantity!(AU, Meter);
+impl_from_quantity!(Kilometer, Meter);
+impl_from_quantity!(Mile, Meter);
+impl_from_quantity!(Meter, Centimeter);
+impl_from_quantity!(Meter, Millimeter);
+impl_from_quantity!(Meter, Micrometer);
+impl_from_quantity!(Meter, Nanometer);
+impl_from_quantity!(Meter, Picometer);
+impl_from_quantity!(Meter, Femtometer);
+impl_from_quantity!(Meter, Inch);
+impl_from_quantity!(Meter, Foot);
+impl_from_quantity!(Meter, Yard);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Kilometer(pub f64);
 pub type Kilometers = Kilometer;
 pub type Kilometre = Kilometer;
@@ -39,21 +40,24 @@ pub type Kilometres = Kilometer;
 
 impl_full_unit!(Kilometer);
 impl_unit_debug!(Kilometer => "km");
-
-impl_from_cf!(AU        <===>                 149597870.70 Kilometer );
-impl_from_cf!(Mile      <===>                     1.609344 Kilometer );
-impl_from_cf!(Kilometer <===>                   100_000.00 Centimeter);
-impl_from_cf!(Kilometer <===>                 1_000_000.00 Millimeter);
-impl_from_cf!(Kilometer <===>             1_000_000_000.00 Micrometer);
-impl_from_cf!(Kilometer <===>         1_000_000_000_000.00 Nanometer );
-impl_from_cf!(Kilometer <===>     1_000_000_000_000_000.00 Picometer );
-impl_from_cf!(Kilometer <===> 1_000_000_000_000_000_000.00 Femtometer);
-impl_from_cf!(Kilometer <===>                    39_370.07 Inch      );
-impl_from_cf!(Kilometer <===>                     3_280.84 Foot      );
-impl_from_cf!(Kilometer <===>                     1_093.61 Yard      );
+impl_quantity!(Kilometer => Meter, 1e3);
+impl_composite_base!(Kilometer);
+
+impl_from_quantity!(AU, Kilometer);
+impl_from_quantity!(Mile, Kilometer);
+impl_from_quantity!(Kilometer, Centimeter);
+impl_from_quantity!(Kilometer, Millimeter);
+impl_from_quantity!(Kilometer, Micrometer);
+impl_from_quantity!(Kilometer, Nanometer);
+impl_from_quantity!(Kilometer, Picometer);
+impl_from_quantity!(Kilometer, Femtometer);
+impl_from_quantity!(Kilometer, Inch);
+impl_from_quantity!(Kilometer, Foot);
+impl_from_quantity!(Kilometer, Yard);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Centimeter(pub f64);
 pub type Centimeters = Centimeter;
 pub type Centimetre = Centimeter;
@@ -61,20 +65,23 @@ pub type Centimetres = Centimeter;
 
 impl_full_unit!(Centimeter);
 impl_unit_debug!(Centimeter => "cm");
-
-impl_from_cf!(Centimeter <===>                 10.00 Millimeter);
-impl_from_cf!(Centimeter <===>             10_000.00 Micrometer);
-impl_from_cf!(Centimeter <===>         10_000_000.00 Nanometer );
-impl_from_cf!(Centimeter <===>     10_000_000_000.00 Picometer );
-impl_from_cf!(Centimeter <===> 10_000_000_000_000.00 Femtometer);
-impl_from_cf!(Inch       <===>                  2.54 Centimeter);
-impl_from_cf!(Foot       <===>                 30.48 Centimeter);
-impl_from_cf!(Yard       <===>                 91.44 Centimeter);
-impl_from_cf!(Mile       <===>             160934.40 Centimeter);
-impl_from_cf!(AU         <===>     14959787070000.00 Centimeter);
+impl_quantity!(Centimeter => Meter, 1e-2);
+impl_composite_base!(Centimeter);
+
+impl_from_quantity!(Centimeter, Millimeter);
+impl_from_quantity!(Centimeter, Micrometer);
+impl_from_quantity!(Centimeter, Nanometer);
+impl_from_quantity!(Centimeter, Picometer);
+impl_from_quantity!(Centimeter, Femtometer);
+impl_from_quantity!(Inch, Centimeter);
+impl_from_quantity!(Foot, Centimeter);
+impl_from_quantity!(Yard, Centimeter);
+impl_from_quantity!(Mile, Centimeter);
+impl_from_quantity!(AU, Centimeter);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Millimeter(pub f64);
 pub type Millimeters = Millimeter;
 pub type Millimetre = Millimeter;
@@ -82,19 +89,22 @@ pub type Millimetres = Millimeter;
 
 impl_full_unit!(Millimeter);
 impl_unit_debug!(Millimeter => "mm");
-
-impl_from_cf!(Millimeter <===>             1_000.0 Micrometer);
-impl_from_cf!(Millimeter <===>         1_000_000.0 Nanometer );
-impl_from_cf!(Millimeter <===>     1_000_000_000.0 Picometer );
-impl_from_cf!(Millimeter <===> 1_000_000_000_000.0 Femtometer);
-impl_from_cf!(Inch       <===>                25.4 Millimeter);
-impl_from_cf!(Foot       <===>               304.8 Millimeter);
-impl_from_cf!(Yard       <===>               914.4 Millimeter);
-impl_from_cf!(Mile       <===>           1609344.0 Millimeter);
-impl_from_cf!(AU         <===>   149597870700000.0 Millimeter);
+impl_quantity!(Millimeter => Meter, 1e-3);
+impl_composite_base!(Millimeter);
+
+impl_from_quantity!(Millimeter, Micrometer);
+impl_from_quantity!(Millimeter, Nanometer);
+impl_from_quantity!(Millimeter, Picometer);
+impl_from_quantity!(Millimeter, Femtometer);
+impl_from_quantity!(Inch, Millimeter);
+impl_from_quantity!(Foot, Millimeter);
+impl_from_quantity!(Yard, Millimeter);
+impl_from_quantity!(Mile, Millimeter);
+impl_from_quantity!(AU, Millimeter);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Micrometer(pub f64);
 pub type Micrometers = Micrometer;
 pub type Micrometre = Micrometer;
@@ -102,37 +112,42 @@ pub type Micrometres = Micrometer;
 
 impl_full_unit!(Micrometer);
 impl_unit_debug!(Micrometer => "um");
-
-impl_from_cf!(Micrometer <===>              1_000.0 Nanometer );
-impl_from_cf!(Micrometer <===>          1_000_000.0 Picometer );
-impl_from_cf!(Micrometer <===>      1_000_000_000.0 Femtometer);
-impl_from_cf!(Inch       <===>              25400.0 Micrometer);
-impl_from_cf!(Foot       <===>             304800.0 Micrometer);
-impl_from_cf!(Yard       <===>             914400.0 Micrometer);
-impl_from_cf!(Mile       <===>         1609344000.0 Micrometer);
-impl_from_cf!(AU         <===> 149597870700000000.0 Micrometer);
+impl_quantity!(Micrometer => Meter, 1e-6);
+impl_composite_base!(Micrometer);
+
+impl_from_quantity!(Micrometer, Nanometer);
+impl_from_quantity!(Micrometer, Picometer);
+impl_from_quantity!(Micrometer, Femtometer);
+impl_from_quantity!(Inch, Micrometer);
+impl_from_quantity!(Foot, Micrometer);
+impl_from_quantity!(Yard, Micrometer);
+impl_from_quantity!(Mile, Micrometer);
+impl_from_quantity!(AU, Micrometer);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Nanometer(pub f64);
 pub type Nanometers = Nanometer;
 pub type Nanometre = Nanometer;
 pub type Nanometres = Nanometer;
 
-
 impl_full_unit!(Nanometer);
-impl_unit_debug!(Nanometer => "um");
-
-impl_from_cf!(Nanometer  <===>                  1000.0 Picometer );
-impl_from_cf!(Nanometer  <===>               1000000.0 Femtometer);
-impl_from_cf!(Inch       <===>              25400000.0 Nanometer );
-impl_from_cf!(Foot       <===>             304800000.0 Nanometer );
-impl_from_cf!(Yard       <===>             914400000.0 Nanometer );
-impl_from_cf!(Mile       <===>         1609344000000.0 Nanometer );
-impl_from_cf!(AU         <===> 149597870700000000000.0 Nanometer );
+impl_unit_debug!(Nanometer => "nm");
+impl_quantity!(Nanometer => Meter, 1e-9);
+impl_composite_base!(Nanometer);
+
+impl_from_quantity!(Nanometer, Picometer);
+impl_from_quantity!(Nanometer, Femtometer);
+impl_from_quantity!(Inch, Nanometer);
+impl_from_quantity!(Foot, Nanometer);
+impl_from_quantity!(Yard, Nanometer);
+impl_from_quantity!(Mile, Nanometer);
+impl_from_quantity!(AU, Nanometer);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Picometer(pub f64);
 pub type Picometers = Picometer;
 pub type Picometre = Picometer;
@@ -140,16 +155,19 @@ pub type Picometres = Picometer;
 
 impl_full_unit!(Picometer);
 impl_unit_debug!(Picometer => "pm");
+impl_quantity!(Picometer => Meter, 1e-12);
+impl_composite_base!(Picometer);
 
-impl_from_cf!(Picometer  <===>                     1000.0 Femtometer);
-impl_from_cf!(Inch       <===>              25400000000.0 Picometer );
-impl_from_cf!(Foot       <===>             304800000000.0 Picometer );
-impl_from_cf!(Yard       <===>             914400000000.0 Picometer );
-impl_from_cf!(Mile       <===>         1609344000000000.0 Picometer );
-impl_from_cf!(AU         <===> 149597870700000000000000.0 Picometer );
+impl_from_quantity!(Picometer, Femtometer);
+impl_from_quantity!(Inch, Picometer);
+impl_from_quantity!(Foot, Picometer);
+impl_from_quantity!(Yard, Picometer);
+impl_from_quantity!(Mile, Picometer);
+impl_from_quantity!(AU, Picometer);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Femtometer(pub f64);
 pub type Femtometers = Femtometer;
 pub type Femtometre = Femtometer;
@@ -157,9 +175,27 @@ pub type Femtometres = Femtometer;
 
 impl_full_unit!(Femtometer);
 impl_unit_debug!(Femtometer => "fm");
-
-impl_from_cf!(Inch       <===>              25400000000000.0 Femtometer);
-impl_from_cf!(Foot       <===>             304800000000000.0 Femtometer);
-impl_from_cf!(Yard       <===>             914400000000000.0 Femtometer);
-impl_from_cf!(Mile       <===>         1609344000000000000.0 Femtometer);
-impl_from_cf!(AU         <===> 149597870700000000000000000.0 Femtometer);
+impl_quantity!(Femtometer => Meter, 1e-15);
+impl_composite_base!(Femtometer);
+
+impl_from_quantity!(Inch, Femtometer);
+impl_from_quantity!(Foot, Femtometer);
+impl_from_quantity!(Yard, Femtometer);
+impl_from_quantity!(Mile, Femtometer);
+impl_from_quantity!(AU, Femtometer);
+
+/// A million meters, as a prefixed `Meter`.
+pub type Megameter = Prefixed<Mega, Meter>;
+pub type Megameters = Megameter;
+pub type Megametre = Megameter;
+pub type Megametres = Megameter;
+/// A billion meters, as a prefixed `Meter`.
+pub type Gigameter = Prefixed<Giga, Meter>;
+pub type Gigameters = Gigameter;
+pub type Gigametre = Gigameter;
+pub type Gigametres = Gigameter;
+/// 10<sup>-18</sup> of a meter, as a prefixed `Meter`.
+pub type Attometer = Prefixed<Atto, Meter>;
+pub type Attometers = Attometer;
+pub type Attometre = Attometer;
+pub type Attometres = Attometer;
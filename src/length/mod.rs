@@ -1,4 +1,49 @@
 //! This module provides newtypes for performant and safe unit-aware calculations with length.
+//!
+//! Like every unit, lengths keep their unit through `abs`, `min`, `max`, `clamp`, `hypot` and rounding, and take
+//! the other operand in any unit of the same dimension.
+//!
+//! ```
+//! use metric::composite::Div;
+//! use metric::length::imperial::Inch;
+//! use metric::length::metric::{Centimeter, Kilometer, Meter, Millimeter};
+//! use metric::time::{Hour, Second};
+//!
+//! assert_eq!(Meter(-2.0).abs(), Meter(2.0));
+//! assert_eq!(Meter(1.0).min(Centimeter(50.0)), Meter(0.5));
+//! assert_eq!(Inch(1.0).max(Millimeter(10.0)), Inch(1.0));
+//! assert_eq!(Meter(3.0).clamp(Centimeter(0.0), Centimeter(200.0)), Meter(2.0));
+//! assert_eq!(Meter(3.0).hypot(Centimeter(400.0)), Meter(5.0));
+//! assert_eq!(Millimeter(12.34).round_to(Millimeter(0.5)), Millimeter(12.5));
+//! assert!((Meter(0.01234).round_to(Millimeter(0.5)) - Meter(0.0125)).abs() < Meter(1e-12));
+//! assert_eq!((Meter(2.5).floor(), Meter(2.5).ceil(), Meter(2.5).round()), (Meter(2.0), Meter(3.0), Meter(3.0)));
+//! assert!(Meter(core::f64::NAN).is_nan() && !Meter(core::f64::INFINITY).is_finite());
+//!
+//! let speed: Div<Meter, Second> = Meter(3.0) / Second(1.0);
+//! assert_eq!(speed.min(Kilometer(3.6) / Hour(1.0)), Meter(1.0) / Second(1.0));
+//! assert_eq!((Meter(1.26) / Second(1.0)).round_to(Centimeter(50.0) / Second(1.0)), Meter(1.5) / Second(1.0));
+//! assert_eq!((Meter(-3.0) / Second(1.0)).abs().hypot(Meter(4.0) / Second(1.0)), Meter(5.0) / Second(1.0));
+//! ```
+//!
+//! The operators work the same way, on values or references, with any primitive number as a scalar.
+//!
+//! ```
+//! use metric::composite::Div;
+//! use metric::length::metric::{Centimeter, Meter};
+//! use metric::time::Second;
+//!
+//! let (a, b) = (Meter(7.0), Meter(2.0));
+//! assert_eq!((-a, &a + &b, &a - b), (Meter(-7.0), Meter(9.0), Meter(5.0)));
+//! assert_eq!((a % b, &a % Centimeter(300.0)), (Meter(1.0), Meter(1.0)));
+//! assert_eq!((a * 2u32, a * 2.0f32, 2usize * a, a / 2i32), (Meter(14.0), Meter(14.0), Meter(14.0), Meter(3.5)));
+//!
+//! let mut speed: Div<Meter, Second> = a / Second(1.0);
+//! speed += b / Second(1.0);
+//! speed -= &(Meter(1.0) / Second(1.0));
+//! speed *= 2u32;
+//! assert_eq!(-speed, Meter(-16.0) / Second(1.0));
+//! assert_eq!(&speed % (Meter(5.0) / Second(1.0)), Meter(1.0) / Second(1.0));
+//! ```
 
 pub mod metric;
 pub mod imperial;
@@ -1,18 +1,144 @@
 #![warn(unused_results)]
 #![allow(unused_macros)]
+#![allow(clippy::needless_return)]
 
 #![no_std]
 
+#[cfg(feature = "libm")]
+extern crate libm;
+
+#[cfg(feature = "approx")]
+#[doc(hidden)]
+pub extern crate approx as __approx;
+
+#[doc(hidden)]
+pub extern crate core as __core;
+
+#[cfg(feature = "derive")]
+extern crate metric_derive;
+
+/// Derives unit-aware operations for a struct whose fields are units or composites.
+///
+/// The derive generates:
+///
+/// * `Add`, `Sub`, `AddAssign` and `SubAssign`, applied field by field.
+/// * `Mul<f64>`, `Div<f64>`, `MulAssign<f64>`, `DivAssign<f64>` and `f64 * Self`, scaling every field.
+/// * `Display`, writing every field with its unit, e.g. `Body { position: 1500m, mass: 2000kg }`.
+/// * `From<Self>` for each struct named in `#[quantities(into(...))]`, converting every field to the unit of the
+///   same field in the target, which is how a struct is moved to a preferred unit system.
+///
+/// Fields marked `#[quantities(skip)]`, such as names, are copied from the left-hand side, left out of `Display` and
+/// moved into the target of a conversion unchanged. Tuple structs work the same way, with their fields written by
+/// position. Generic structs are not supported. Requires the `derive` feature.
+///
+/// The generated code refers to this crate as `::metric`. A crate that depends on it under another name says so with
+/// `#[quantities(crate = ::other_name)]`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric;
+///
+/// use metric::composite::Div;
+/// use metric::length::imperial::Foot;
+/// use metric::length::metric::Meter;
+/// use metric::time::{Hour, Second};
+///
+/// #[derive(Clone, Copy, Quantities)]
+/// #[quantities(into(ImperialState))]
+/// struct State {
+///     position: Meter,
+///     velocity: Div<Meter, Second>,
+/// }
+///
+/// #[derive(Clone, Copy, Quantities)]
+/// struct ImperialState {
+///     position: Foot,
+///     velocity: Div<Foot, Hour>,
+/// }
+///
+/// fn step<S>(state: S, rate: S, dt: f64) -> S
+///     where S: std::ops::Add<Output = S> + std::ops::Mul<f64, Output = S>
+/// {
+///     state + rate * dt
+/// }
+///
+/// fn main() {
+///     let state = State { position: Meter(0.0), velocity: Meter(1.0) / Second(1.0) };
+///     let rate = State { position: Meter(1.0), velocity: Meter(0.5) / Second(1.0) };
+///     let state = step(state, rate, 2.0);
+///     assert_eq!(state.to_string(), "State { position: 2m, velocity: 2 (m)/(s) }");
+///     let imperial = ImperialState::from(state);
+///     assert_eq!(imperial.position.0.round(), 7.0);
+/// }
+/// ```
+///
+/// Tuple structs, skipped fields and a renamed crate:
+///
+/// ```
+/// #[macro_use]
+/// extern crate metric as units;
+///
+/// use units::length::metric::Meter;
+/// use units::mass::metric::Kilogram;
+///
+/// #[derive(Clone, Copy, Quantities)]
+/// #[quantities(crate = ::units)]
+/// struct Cargo(Meter, #[quantities(skip)] u32, Kilogram);
+///
+/// fn main() {
+///     let mut cargo = Cargo(Meter(2.0), 7, Kilogram(10.0)) + Cargo(Meter(1.0), 3, Kilogram(5.0));
+///     assert_eq!(cargo.to_string(), "Cargo(3m, 15kg)");
+///     assert_eq!(cargo.1, 7);
+///
+///     cargo *= 2.0;
+///     let cargo = 0.5 * cargo;
+///     assert_eq!((cargo.0, cargo.1, cargo.2), (Meter(3.0), 7, Kilogram(15.0)));
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use metric_derive::Quantities;
+
 #[macro_use]
 mod implmacro;
+#[macro_use]
+#[doc(hidden)]
+pub mod approx_eq;
 
+#[cfg(feature = "temperature")]
 pub mod temperature;
+#[cfg(feature = "length")]
 pub mod length;
+#[cfg(feature = "time")]
 pub mod time;
+#[cfg(feature = "mass")]
 pub mod mass;
+#[cfg(feature = "angle")]
+pub mod angle;
+#[cfg(feature = "solid_angle")]
+pub mod solid_angle;
+#[cfg(feature = "electrical")]
 pub mod electrical;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
 pub mod force;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+pub mod energy;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+pub mod power;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+pub mod pressure;
 
+#[cfg(all(feature = "length", feature = "mass"))]
 pub mod constants;
 
 pub mod composite;
+#[doc(hidden)]
+pub mod dim;
+pub mod prefix;
+mod define;
+mod expr;
+pub mod dimension;
+pub mod kind;
+pub mod total;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod math;
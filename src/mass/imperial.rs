@@ -1,34 +1,41 @@
 //! Defines the imperial mass standards as newtypes
 
-use core::{self, fmt};
-
-use composite::UnitName;
+use mass::metric::Kilogram;
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Ton(pub f64);
 pub type Tons = Ton;
 
 impl_full_unit!(Ton);
 impl_unit_debug!(Ton => "(imperial ton)");
+impl_quantity!(Ton => Kilogram, 907.18474);
+impl_composite_base!(Ton);
 
-impl_from_cf!(Ton <===>  2000.0 Pound);
-impl_from_cf!(Ton <===> 32000.0 Ounce);
+impl_from_quantity!(Ton, Pound);
+impl_from_quantity!(Ton, Ounce);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Pound(pub f64);
 pub type Pounds = Pound;
 
 impl_full_unit!(Pound);
 impl_unit_debug!(Pound => "lb");
+impl_quantity!(Pound => Kilogram, 0.45359237);
+impl_composite_base!(Pound);
 
-impl_from_cf!(Pound <===> 16.0 Ounce);
+impl_from_quantity!(Pound, Ounce);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Ounce(pub f64);
 pub type Ounces = Ounce;
 
 impl_full_unit!(Ounce);
 impl_unit_debug!(Ounce => "oz");
+impl_quantity!(Ounce => Kilogram, 0.028349523125);
+impl_composite_base!(Ounce);
@@ -1,4 +1,28 @@
 //! This module provides newtypes for performant and safe unit-aware calculations with mass.
+//!
+//! Masses sum into any unit they convert to, and every unit and composite starts from `ZERO` or `Default`.
+//!
+//! ```
+//! use metric::composite::{Div, Mul};
+//! use metric::length::metric::Meter;
+//! use metric::mass::imperial::Pound;
+//! use metric::mass::metric::{Gram, Kilogram};
+//!
+//! let parts = [Gram(250.0), Gram(500.0), Gram(1250.0)];
+//! assert_eq!(parts.iter().sum::<Kilogram>(), Kilogram(2.0));
+//! assert_eq!(parts.iter().map(|&g| Kilogram::from(g)).sum::<Kilogram>(), Kilogram(2.0));
+//! assert_eq!(vec![Pound(1.0); 2].into_iter().sum::<Kilogram>(), Kilogram(2.0 * 0.45359237));
+//! assert_eq!(Kilogram::ZERO + Kilogram::ONE, Kilogram::default() + Kilogram(1.0));
+//!
+//! let loads = [Mul::<Kilogram, Meter>::from_value(1.0), Mul::from_value(2.0)];
+//! let grams = [Mul::<Gram, Meter>::from_value(500.0)];
+//! let total = loads.iter().sum::<Mul<Kilogram, Meter>>() + grams.iter().copied().sum::<Mul<Kilogram, Meter>>();
+//! assert_eq!(total, Mul::<Kilogram, Meter>::from_value(3.5));
+//! assert_eq!(Mul::<Kilogram, Meter>::default(), Mul::<Kilogram, Meter>::ZERO);
+//!
+//! let ratios = [Div::<Kilogram, Kilogram>::from_value(2.0), Div::from_value(3.0)];
+//! assert_eq!(ratios.iter().product::<Div<Kilogram, Kilogram>>(), Div::<Kilogram, Kilogram>::from_value(6.0));
+//! ```
 
 pub mod metric;
 pub mod imperial;
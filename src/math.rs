@@ -0,0 +1,78 @@
+//! Floating point functions used by the unit math APIs.
+//!
+//! These are backed by `std` when the `std` feature is enabled, and by `libm` otherwise, so that
+//! `no_std` builds can still take square roots of areas or use trigonometry.
+//!
+//! ```
+//! use metric::angle::Degree;
+//! use metric::composite::{Div, Mul};
+//! use metric::length::metric::Meter;
+//!
+//! let area: Mul<Meter, Meter> = Meter(3.0) * Meter(3.0);
+//! assert_eq!(area.sqrt(), Meter(3.0));
+//! let volume: Mul<Meter, Mul<Meter, Meter>> = Meter(2.0) * area;
+//! assert_eq!(volume.cbrt(), Meter(18.0f64.cbrt()));
+//! assert_eq!(Meter(3.0).hypot(Meter(4.0)), Meter(5.0));
+//! assert!((Degree(30.0).sin() - 0.5).abs() < 1e-12);
+//! let ratio = Div::<Meter, Meter>::from_value;
+//! assert_eq!(ratio(9.0).powf(0.5), ratio(3.0));
+//! ```
+
+#[cfg(feature = "std")]
+pub use self::imp::*;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+pub use libm::{acos, asin, atan, atan2, cbrt, ceil, cos, floor, hypot, round, sin, sqrt, tan, trunc};
+
+/// Raises `x` to the floating point power `y`.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[inline(always)]
+pub fn powf(x: f64, y: f64) -> f64 {
+    libm::pow(x, y)
+}
+
+#[cfg(feature = "std")]
+mod imp {
+    extern crate std;
+
+    macro_rules! std_fn {
+        ($(#[$attr:meta])* $name:ident($x:ident $(, $arg:ident)*)) => {
+            $(#[$attr])*
+            #[inline(always)]
+            pub fn $name($x: f64 $(, $arg: f64)*) -> f64 {
+                $x.$name($($arg),*)
+            }
+        }
+    }
+
+    std_fn!(/// Returns the square root of `x`.
+            sqrt(x));
+    std_fn!(/// Returns the cube root of `x`.
+            cbrt(x));
+    std_fn!(/// Returns `sqrt(x * x + y * y)` without undue overflow or underflow.
+            hypot(x, y));
+    std_fn!(/// Raises `x` to the floating point power `y`.
+            powf(x, y));
+    std_fn!(/// Returns the sine of `x` (in radians).
+            sin(x));
+    std_fn!(/// Returns the cosine of `x` (in radians).
+            cos(x));
+    std_fn!(/// Returns the tangent of `x` (in radians).
+            tan(x));
+    std_fn!(/// Returns the arcsine of `x`, in radians.
+            asin(x));
+    std_fn!(/// Returns the arccosine of `x`, in radians.
+            acos(x));
+    std_fn!(/// Returns the arctangent of `x`, in radians.
+            atan(x));
+    std_fn!(/// Returns the four quadrant arctangent of `y` and `x`, in radians.
+            atan2(y, x));
+    std_fn!(/// Returns the largest integer less than or equal to `x`.
+            floor(x));
+    std_fn!(/// Returns the smallest integer greater than or equal to `x`.
+            ceil(x));
+    std_fn!(/// Returns the nearest integer to `x`, rounding half-way cases away from zero.
+            round(x));
+    std_fn!(/// Returns the integer part of `x`.
+            trunc(x));
+}
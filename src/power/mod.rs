@@ -0,0 +1,97 @@
+//! Units of power.
+//!
+//! Every unit of power converts to and from an energy per time, and a power times a time converts into an energy, so
+//! `Joule / Second` gives `Watt` and `Watt * Hour` gives `WattHour`.
+//!
+//! ```
+//! use metric::energy::{Joule, WattHour};
+//! use metric::power::{Kilowatt, MechanicalHorsepower, Watt};
+//! use metric::time::{Hour, Second};
+//!
+//! let power: Watt = (Joule(600.0) / Second(2.0)).into();
+//! assert_eq!(power, Watt(300.0));
+//! assert_eq!(WattHour::from(power * Hour(2.0)), WattHour(600.0));
+//! assert_eq!(Kilowatt::from(Watt(1500.0)), Kilowatt::new(1.5));
+//! assert_eq!(Watt::from(MechanicalHorsepower(1.0)).0.round(), 746.0);
+//! ```
+
+use prefix::{Kilo, Mega, Prefixed};
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Watt(pub f64);
+pub type Watts = Watt;
+
+impl_full_unit!(Watt);
+impl_unit_debug!(Watt => "W");
+impl_quantity!(Watt => Watt, 1.0);
+impl_composite_base!(Watt);
+impl_from_composite!(Watt);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct MechanicalHorsepower(pub f64);
+
+impl_full_unit!(MechanicalHorsepower);
+impl_unit_debug!(MechanicalHorsepower => "hp");
+impl_quantity!(MechanicalHorsepower => Watt, 550.0 * 0.3048 * 4.4482216152605);
+impl_composite_base!(MechanicalHorsepower);
+impl_from_composite!(MechanicalHorsepower);
+
+impl_from_quantity!(MechanicalHorsepower, Watt);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct MetricHorsepower(pub f64);
+
+impl_full_unit!(MetricHorsepower);
+impl_unit_debug!(MetricHorsepower => "PS");
+impl_quantity!(MetricHorsepower => Watt, 735.49875);
+impl_composite_base!(MetricHorsepower);
+impl_from_composite!(MetricHorsepower);
+
+impl_from_quantity!(MetricHorsepower, Watt);
+impl_from_quantity!(MechanicalHorsepower, MetricHorsepower);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct BtuPerHour(pub f64);
+
+impl_full_unit!(BtuPerHour);
+impl_unit_debug!(BtuPerHour => "BTU/h");
+impl_quantity!(BtuPerHour => Watt, 1_055.05585262 / 3_600.0);
+impl_composite_base!(BtuPerHour);
+impl_from_composite!(BtuPerHour);
+
+impl_from_quantity!(Watt, BtuPerHour);
+impl_from_quantity!(MechanicalHorsepower, BtuPerHour);
+impl_from_quantity!(MetricHorsepower, BtuPerHour);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct TonOfRefrigeration(pub f64);
+pub type TonsOfRefrigeration = TonOfRefrigeration;
+
+impl_full_unit!(TonOfRefrigeration);
+impl_unit_debug!(TonOfRefrigeration => "TR");
+impl_quantity!(TonOfRefrigeration => Watt, 12_000.0 * 1_055.05585262 / 3_600.0);
+impl_composite_base!(TonOfRefrigeration);
+impl_from_composite!(TonOfRefrigeration);
+
+impl_from_quantity!(TonOfRefrigeration, Watt);
+impl_from_quantity!(TonOfRefrigeration, MechanicalHorsepower);
+impl_from_quantity!(TonOfRefrigeration, MetricHorsepower);
+impl_from_quantity!(TonOfRefrigeration, BtuPerHour);
+
+/// A thousand watts, as a prefixed `Watt`.
+pub type Kilowatt = Prefixed<Kilo, Watt>;
+pub type Kilowatts = Kilowatt;
+
+/// A million watts, as a prefixed `Watt`.
+pub type Megawatt = Prefixed<Mega, Watt>;
+pub type Megawatts = Megawatt;
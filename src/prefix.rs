@@ -0,0 +1,311 @@
+//! Provides the SI prefixes, from quecto to quetta, and `Prefixed` for applying them to coherent units.
+//!
+//! `Prefixed<Mega, Meter>` is a megameter, `Prefixed<Nano, Second>` a nanosecond and `Prefixed<Micro, Gram>` a
+//! microgram. Prefixed units convert to and from the other units of their dimension, including other prefixes of the
+//! same unit, print with their SI symbol (`Mm`, `ns`, `µg`) and take part in composites like any other unit.
+//! Prefixes apply to the units that implement `Coherent`, so a scale with an offset such as `Celsius` can't be
+//! prefixed.
+//!
+//! ```
+//! use metric::prefix::{Mega, Prefixed};
+//! use metric::length::metric::{Kilometer, Meter};
+//! use metric::time::Nanosecond;
+//!
+//! let distance = Prefixed::<Mega, Meter>::new(1.5) + Kilometer(500.0);
+//! assert_eq!(format!("{:?}", distance), "2Mm");
+//! assert_eq!(Meter::from(distance), Meter(2e6));
+//! println!("{:?}", distance / Nanosecond::new(1.0));
+//! ```
+//!
+//! ```compile_fail
+//! use metric::composite::Quantity;
+//! use metric::prefix::{Kilo, Prefixed};
+//! use metric::temperature::Celsius;
+//!
+//! let _ = Prefixed::<Kilo, Celsius>::new(1.0).to_base();
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use composite::{convert_const, DivSame, Quantity, UnitName};
+
+#[cfg(feature = "length")]
+use length::metric::Meter;
+#[cfg(feature = "mass")]
+use mass::metric::Gram;
+#[cfg(feature = "temperature")]
+use temperature::Kelvin;
+#[cfg(feature = "time")]
+use time::Second;
+#[cfg(feature = "angle")]
+use angle::Radian;
+#[cfg(feature = "solid_angle")]
+use solid_angle::Steradian;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use force::Newton;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use energy::Joule;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use power::Watt;
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+use pressure::Pascal;
+#[cfg(feature = "electrical")]
+use electrical::{Ampere, Coulomb, Farad, Henry, Ohm, Siemens, Volt};
+
+/// An SI prefix, scaling a unit by a power of ten.
+pub trait Prefix: Copy {
+    /// The factor this prefix multiplies the unit by.
+    const FACTOR: f64;
+    /// The symbol written before the unit's symbol.
+    const SYMBOL: &'static str;
+}
+
+/// A unit that SI prefixes apply to: one of the coherent SI units, the gram, or the base unit of a dimension declared
+/// with `define_dimension!`.
+///
+/// Each of these has no offset, so a prefix scales it by a plain multiplication.
+pub trait Coherent: Quantity {}
+
+macro_rules! prefixes {
+    ($($(#[$attr:meta])* $name:ident = $factor:expr, $symbol:expr;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl Prefix for $name {
+                const FACTOR: f64 = $factor;
+                const SYMBOL: &'static str = $symbol;
+            }
+        )*
+    }
+}
+
+prefixes! {
+    /// 10<sup>30</sup>
+    Quetta = 1e30, "Q";
+    /// 10<sup>27</sup>
+    Ronna = 1e27, "R";
+    /// 10<sup>24</sup>
+    Yotta = 1e24, "Y";
+    /// 10<sup>21</sup>
+    Zetta = 1e21, "Z";
+    /// 10<sup>18</sup>
+    Exa = 1e18, "E";
+    /// 10<sup>15</sup>
+    Peta = 1e15, "P";
+    /// 10<sup>12</sup>
+    Tera = 1e12, "T";
+    /// 10<sup>9</sup>
+    Giga = 1e9, "G";
+    /// 10<sup>6</sup>
+    Mega = 1e6, "M";
+    /// 10<sup>3</sup>
+    Kilo = 1e3, "k";
+    /// 10<sup>2</sup>
+    Hecto = 1e2, "h";
+    /// 10<sup>1</sup>
+    Deca = 1e1, "da";
+    /// 10<sup>-1</sup>
+    Deci = 1e-1, "d";
+    /// 10<sup>-2</sup>
+    Centi = 1e-2, "c";
+    /// 10<sup>-3</sup>
+    Milli = 1e-3, "m";
+    /// 10<sup>-6</sup>
+    Micro = 1e-6, "µ";
+    /// 10<sup>-9</sup>
+    Nano = 1e-9, "n";
+    /// 10<sup>-12</sup>
+    Pico = 1e-12, "p";
+    /// 10<sup>-15</sup>
+    Femto = 1e-15, "f";
+    /// 10<sup>-18</sup>
+    Atto = 1e-18, "a";
+    /// 10<sup>-21</sup>
+    Zepto = 1e-21, "z";
+    /// 10<sup>-24</sup>
+    Yocto = 1e-24, "y";
+    /// 10<sup>-27</sup>
+    Ronto = 1e-27, "r";
+    /// 10<sup>-30</sup>
+    Quecto = 1e-30, "q";
+}
+
+//invokes the given macro once for every prefix, along with its symbol
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __prefixes {
+    ($mac:ident!($($args:tt)*)) => {
+        $crate::$mac!($($args)*, Quetta, "Q");
+        $crate::$mac!($($args)*, Ronna, "R");
+        $crate::$mac!($($args)*, Yotta, "Y");
+        $crate::$mac!($($args)*, Zetta, "Z");
+        $crate::$mac!($($args)*, Exa, "E");
+        $crate::$mac!($($args)*, Peta, "P");
+        $crate::$mac!($($args)*, Tera, "T");
+        $crate::$mac!($($args)*, Giga, "G");
+        $crate::$mac!($($args)*, Mega, "M");
+        $crate::$mac!($($args)*, Kilo, "k");
+        $crate::$mac!($($args)*, Hecto, "h");
+        $crate::$mac!($($args)*, Deca, "da");
+        $crate::$mac!($($args)*, Deci, "d");
+        $crate::$mac!($($args)*, Centi, "c");
+        $crate::$mac!($($args)*, Milli, "m");
+        $crate::$mac!($($args)*, Micro, "µ");
+        $crate::$mac!($($args)*, Nano, "n");
+        $crate::$mac!($($args)*, Pico, "p");
+        $crate::$mac!($($args)*, Femto, "f");
+        $crate::$mac!($($args)*, Atto, "a");
+        $crate::$mac!($($args)*, Zepto, "z");
+        $crate::$mac!($($args)*, Yocto, "y");
+        $crate::$mac!($($args)*, Ronto, "r");
+        $crate::$mac!($($args)*, Quecto, "q");
+    }
+}
+
+/// A coherent unit `U` scaled by the SI prefix `P`, holding the number of prefixed units.
+#[repr(transparent)]
+pub struct Prefixed<P, U>(pub f64, pub PhantomData<(P, U)>);
+
+impl<P, U> Clone for Prefixed<P, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P, U> Copy for Prefixed<P, U> {}
+
+impl<P, U> Prefixed<P, U> {
+    /// Creates a value of `val` prefixed units.
+    #[inline(always)]
+    pub const fn new(val: f64) -> Prefixed<P, U> {
+        Prefixed(val, PhantomData)
+    }
+}
+
+impl_quantity!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q> => Q::Base, P::FACTOR * Q::SCALE, Q::OFFSET);
+impl_composite_base!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>);
+//only units without an offset add to a prefixed unit, so a gauge reading or a temperature scale can't shift it by its
+//reference point
+impl_basic_ops!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>, DivSame<Base = Q::Base>, convert_const);
+impl_rem!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>, DivSame<Base = Q::Base>, convert_const);
+impl_scalar_ops!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>);
+impl_partial_ord!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>, Quantity<Base = Q::Base>, convert_const);
+impl_math!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>, Quantity<Base = Q::Base>, convert_const);
+impl_approx_eq!(@generic [P: Prefix, Q: Coherent] Prefixed<P, Q>, Quantity<Base = Q::Base>, convert_const);
+
+//a prefixed unit divides into a plain number exactly when its unit does
+impl<P, U> DivSame for Prefixed<P, U>
+    where P: Prefix,
+          U: Coherent + DivSame
+{}
+
+//generic over the unit, rather than one impl per prefix and unit, so prefixes of units defined outside this crate
+//print too
+impl<P, U> fmt::Debug for Prefixed<P, U>
+    where P: Prefix,
+          U: UnitName
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.0, P::SYMBOL)?;
+        U::write_unit_static(f)
+    }
+}
+
+//gives every prefix of a unit its symbol, e.g. `km` for `Prefixed<Kilo, Meter>`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_prefixed_name {
+    ($unit:ty, $symbol:literal, $prefix:ident, $prefix_symbol:literal) => {
+        impl UnitName for Prefixed<$prefix, $unit> {
+            fn get_unit(&self) -> &'static str {
+                concat!($prefix_symbol, $symbol)
+            }
+            fn get_unit_static() -> &'static str {
+                concat!($prefix_symbol, $symbol)
+            }
+            fn write_unit_static(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(concat!($prefix_symbol, $symbol))
+            }
+        }
+    }
+}
+
+//converts a prefixed unit to and from a built-in unit of the same dimension
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_prefixed_same {
+    ($unit:ty, $other:ty) => {
+        impl<P: Prefix> From<Prefixed<P, $unit>> for $other {
+            fn from(f: Prefixed<P, $unit>) -> Self {
+                convert_const(f)
+            }
+        }
+        impl<'a, P: Prefix> From<&'a Prefixed<P, $unit>> for $other {
+            fn from(f: &'a Prefixed<P, $unit>) -> Self {
+                convert_const(*f)
+            }
+        }
+        impl<P: Prefix> From<$other> for Prefixed<P, $unit> {
+            fn from(f: $other) -> Self {
+                convert_const(f)
+            }
+        }
+        impl<'a, P: Prefix> From<&'a $other> for Prefixed<P, $unit> {
+            fn from(f: &'a $other) -> Self {
+                convert_const(*f)
+            }
+        }
+    }
+}
+
+macro_rules! impl_prefixed_unit {
+    ($unit:ident, $symbol:literal, $units:ident) => {
+        impl Coherent for $unit {}
+        $crate::__prefixes!(impl_prefixed_name!($unit, $symbol));
+        $crate::$units!(impl_prefixed_same!($unit));
+    };
+    //for a unit that is the only one of its dimension
+    ($unit:ident, $symbol:literal) => {
+        impl Coherent for $unit {}
+        $crate::__prefixes!(impl_prefixed_name!($unit, $symbol));
+        $crate::impl_prefixed_same!($unit, $unit);
+    };
+}
+
+#[cfg(feature = "length")]
+impl_prefixed_unit!(Meter, "m", __length_units);
+#[cfg(feature = "mass")]
+impl_prefixed_unit!(Gram, "g", __mass_units);
+#[cfg(feature = "time")]
+impl_prefixed_unit!(Second, "s", __time_units);
+#[cfg(feature = "temperature")]
+impl_prefixed_unit!(Kelvin, "K", __temperature_units);
+#[cfg(feature = "angle")]
+impl_prefixed_unit!(Radian, "rad", __angle_units);
+#[cfg(feature = "solid_angle")]
+impl_prefixed_unit!(Steradian, "sr", __solid_angle_units);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_prefixed_unit!(Newton, "N", __force_units);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_prefixed_unit!(Joule, "J", __energy_units);
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Ampere, "A");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Volt, "V");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Ohm, "Ω");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Siemens, "S");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Coulomb, "C");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Farad, "F");
+#[cfg(feature = "electrical")]
+impl_prefixed_unit!(Henry, "H");
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_prefixed_unit!(Watt, "W", __power_units);
+#[cfg(all(feature = "length", feature = "mass", feature = "time"))]
+impl_prefixed_unit!(Pascal, "Pa", __pressure_units);
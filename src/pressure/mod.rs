@@ -0,0 +1,312 @@
+//! Units of pressure and stress.
+//!
+//! Every absolute unit of pressure converts to and from a force per area, such as the `Div<Newton, Mul<Meter, Meter>>`
+//! produced by dividing a force by an area.
+//!
+//! Gauge scales, such as `PsiGauge`, measure pressure relative to a reference, which is a standard atmosphere when
+//! converting between them. A gauge reading is not a pressure: it converts to and from the absolute units only through
+//! `from_absolute` and `to_absolute`, which name the reference, such as `Atmosphere(1.0)` or a measured ambient
+//! pressure. Only a difference in an absolute unit can be added to or subtracted from a reading, and two readings
+//! subtract to a difference in pascals. Readings can't be scaled or take part in composites.
+//!
+//! ```
+//! use metric::composite::Quantity;
+//! use metric::force::Newton;
+//! use metric::length::metric::Meter;
+//! use metric::pressure::{Atmosphere, Bar, BarGauge, Kilopascal, Millibar, Pascal, Psi, PsiGauge};
+//!
+//! let pressure: Pascal = (Newton(500.0) / (Meter(0.5) * Meter(0.1))).into();
+//! assert_eq!(pressure, Pascal(10_000.0));
+//! assert_eq!(Kilopascal::from(Bar(1.0)), Kilopascal::new(100.0));
+//! assert_eq!(PsiGauge(0.0).to_absolute(Atmosphere(1.0)), Atmosphere(1.0));
+//! assert!((PsiGauge(32.0).to_absolute(Atmosphere(1.0)).convert::<Psi>().0 - 46.696).abs() < 1e-3);
+//! assert_eq!(BarGauge::from(PsiGauge(0.0)), BarGauge(0.0));
+//!
+//! assert_eq!(PsiGauge(30.0) + Psi(2.0), PsiGauge(32.0));
+//! assert_eq!(BarGauge(2.0) - BarGauge(0.5), Pascal(150_000.0));
+//! assert_eq!(BarGauge::from_absolute(Bar(3.0), Bar(1.0)), BarGauge(2.0));
+//! assert_eq!(BarGauge(2.0).to_absolute(Millibar(900.0)), Millibar(2900.0));
+//! ```
+//!
+//! ```compile_fail
+//! use metric::length::metric::Meter;
+//! use metric::pressure::PsiGauge;
+//!
+//! let _ = PsiGauge(30.0) * (Meter(1.0) * Meter(1.0));
+//! ```
+//!
+//! ```compile_fail
+//! use metric::pressure::{Pascal, PsiGauge};
+//!
+//! let _ = Pascal(0.0) + PsiGauge(0.0);
+//! ```
+
+use core::ops;
+
+use composite::{convert_raw, DivSame, Quantity};
+use prefix::{Kilo, Mega, Prefixed};
+
+//one standard atmosphere, the reference of the gauge scales, in pascals
+const ATMOSPHERE: f64 = 101_325.0;
+//one pound-force per square inch, in pascals
+const PSI: f64 = 4.4482216152605 / (0.0254 * 0.0254);
+//one torr, in pascals
+const TORR: f64 = ATMOSPHERE / 760.0;
+
+//gives a gauge scale its arithmetic: a pressure difference in an absolute unit moves a reading along the scale, and two
+//readings on any of the given gauge scales subtract to a difference in pascals
+macro_rules! impl_gauge_ops {
+    ($gauge:ident => $($other:ident),+) => {
+        impl $gauge {
+            /// Returns the reading on this scale of the absolute `pressure`, taken relative to `reference` rather than
+            /// a standard atmosphere.
+            #[inline(always)]
+            pub fn from_absolute<P, R>(pressure: P, reference: R) -> $gauge
+                where P: DivSame<Base = Pascal>,
+                      R: DivSame<Base = Pascal>
+            {
+                $gauge((pascals(pressure) - pascals(reference)) / <$gauge as Quantity>::SCALE)
+            }
+
+            /// Returns the absolute pressure of this reading, taken relative to `reference` rather than a standard
+            /// atmosphere, in the unit of `reference`.
+            #[inline(always)]
+            pub fn to_absolute<R>(self, reference: R) -> R
+                where R: DivSame<Base = Pascal>
+            {
+                R::new(convert_raw::<Pascal, R>(self.0 * <$gauge as Quantity>::SCALE + pascals(reference)))
+            }
+        }
+
+        impl<P> ops::Add<P> for $gauge
+            where P: DivSame<Base = Pascal>
+        {
+            type Output = $gauge;
+
+            fn add(self, difference: P) -> $gauge {
+                $gauge(self.0 + pascals(difference) / <$gauge as Quantity>::SCALE)
+            }
+        }
+        impl<P> ops::AddAssign<P> for $gauge
+            where P: DivSame<Base = Pascal>
+        {
+            fn add_assign(&mut self, difference: P) {
+                self.0 += pascals(difference) / <$gauge as Quantity>::SCALE;
+            }
+        }
+        impl<P> ops::Sub<P> for $gauge
+            where P: DivSame<Base = Pascal>
+        {
+            type Output = $gauge;
+
+            fn sub(self, difference: P) -> $gauge {
+                $gauge(self.0 - pascals(difference) / <$gauge as Quantity>::SCALE)
+            }
+        }
+        impl<P> ops::SubAssign<P> for $gauge
+            where P: DivSame<Base = Pascal>
+        {
+            fn sub_assign(&mut self, difference: P) {
+                self.0 -= pascals(difference) / <$gauge as Quantity>::SCALE;
+            }
+        }
+        $(
+            impl ops::Sub<$other> for $gauge {
+                type Output = Pascal;
+
+                fn sub(self, other: $other) -> Pascal {
+                    Pascal(self.0 * <$gauge as Quantity>::SCALE - other.0 * <$other as Quantity>::SCALE)
+                }
+            }
+        )+
+    }
+}
+
+//the size of an absolute pressure or pressure difference, in pascals
+#[inline(always)]
+fn pascals<P>(pressure: P) -> f64
+    where P: DivSame<Base = Pascal>
+{
+    pressure.inner() * P::SCALE
+}
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Pascal(pub f64);
+pub type Pascals = Pascal;
+
+impl_full_unit!(Pascal);
+impl_unit_debug!(Pascal => "Pa");
+impl_quantity!(Pascal => Pascal, 1.0);
+impl_composite_base!(Pascal);
+impl_from_composite!(Pascal);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Bar(pub f64);
+pub type Bars = Bar;
+
+impl_full_unit!(Bar);
+impl_unit_debug!(Bar => "bar");
+impl_quantity!(Bar => Pascal, 100_000.0);
+impl_composite_base!(Bar);
+impl_from_composite!(Bar);
+
+impl_from_quantity!(Bar, Pascal);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Millibar(pub f64);
+pub type Millibars = Millibar;
+
+impl_full_unit!(Millibar);
+impl_unit_debug!(Millibar => "mbar");
+impl_quantity!(Millibar => Pascal, 100.0);
+impl_composite_base!(Millibar);
+impl_from_composite!(Millibar);
+
+impl_from_quantity!(Millibar, Pascal);
+impl_from_quantity!(Bar, Millibar);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Atmosphere(pub f64);
+pub type Atmospheres = Atmosphere;
+
+impl_full_unit!(Atmosphere);
+impl_unit_debug!(Atmosphere => "atm");
+impl_quantity!(Atmosphere => Pascal, ATMOSPHERE);
+impl_composite_base!(Atmosphere);
+impl_from_composite!(Atmosphere);
+
+impl_from_quantity!(Atmosphere, Pascal);
+impl_from_quantity!(Atmosphere, Bar);
+impl_from_quantity!(Atmosphere, Millibar);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Psi(pub f64);
+
+impl_full_unit!(Psi);
+impl_unit_debug!(Psi => "psi");
+impl_quantity!(Psi => Pascal, PSI);
+impl_composite_base!(Psi);
+impl_from_composite!(Psi);
+
+impl_from_quantity!(Psi, Pascal);
+impl_from_quantity!(Bar, Psi);
+impl_from_quantity!(Psi, Millibar);
+impl_from_quantity!(Atmosphere, Psi);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Torr(pub f64);
+
+impl_full_unit!(Torr);
+impl_unit_debug!(Torr => "Torr");
+impl_quantity!(Torr => Pascal, TORR);
+impl_composite_base!(Torr);
+impl_from_composite!(Torr);
+
+impl_from_quantity!(Torr, Pascal);
+impl_from_quantity!(Bar, Torr);
+impl_from_quantity!(Torr, Millibar);
+impl_from_quantity!(Atmosphere, Torr);
+impl_from_quantity!(Psi, Torr);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct MillimeterOfMercury(pub f64);
+pub type MillimetersOfMercury = MillimeterOfMercury;
+
+impl_full_unit!(MillimeterOfMercury);
+impl_unit_debug!(MillimeterOfMercury => "mmHg");
+impl_quantity!(MillimeterOfMercury => Pascal, 133.322387415);
+impl_composite_base!(MillimeterOfMercury);
+impl_from_composite!(MillimeterOfMercury);
+
+impl_from_quantity!(MillimeterOfMercury, Pascal);
+impl_from_quantity!(Bar, MillimeterOfMercury);
+impl_from_quantity!(MillimeterOfMercury, Millibar);
+impl_from_quantity!(Atmosphere, MillimeterOfMercury);
+impl_from_quantity!(Psi, MillimeterOfMercury);
+impl_from_quantity!(MillimeterOfMercury, Torr);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct InchOfWater(pub f64);
+pub type InchesOfWater = InchOfWater;
+
+impl_full_unit!(InchOfWater);
+impl_unit_debug!(InchOfWater => "inH2O");
+impl_quantity!(InchOfWater => Pascal, 249.08891);
+impl_composite_base!(InchOfWater);
+impl_from_composite!(InchOfWater);
+
+impl_from_quantity!(InchOfWater, Pascal);
+impl_from_quantity!(Bar, InchOfWater);
+impl_from_quantity!(InchOfWater, Millibar);
+impl_from_quantity!(Atmosphere, InchOfWater);
+impl_from_quantity!(Psi, InchOfWater);
+impl_from_quantity!(InchOfWater, Torr);
+impl_from_quantity!(InchOfWater, MillimeterOfMercury);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct KilopascalGauge(pub f64);
+
+impl_unit!(KilopascalGauge);
+impl_consts!(KilopascalGauge);
+impl_unit_debug!(KilopascalGauge => "kPag");
+impl_quantity!(KilopascalGauge => Pascal, 1_000.0, ATMOSPHERE);
+impl_partial_ord!(KilopascalGauge);
+impl_approx_eq!(KilopascalGauge);
+impl_gauge_ops!(KilopascalGauge => KilopascalGauge, BarGauge, PsiGauge);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct BarGauge(pub f64);
+
+impl_unit!(BarGauge);
+impl_consts!(BarGauge);
+impl_unit_debug!(BarGauge => "barg");
+impl_quantity!(BarGauge => Pascal, 100_000.0, ATMOSPHERE);
+impl_partial_ord!(BarGauge);
+impl_approx_eq!(BarGauge);
+impl_gauge_ops!(BarGauge => KilopascalGauge, BarGauge, PsiGauge);
+
+impl_from_quantity!(BarGauge, KilopascalGauge);
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct PsiGauge(pub f64);
+
+impl_unit!(PsiGauge);
+impl_consts!(PsiGauge);
+impl_unit_debug!(PsiGauge => "psig");
+impl_quantity!(PsiGauge => Pascal, PSI, ATMOSPHERE);
+impl_partial_ord!(PsiGauge);
+impl_approx_eq!(PsiGauge);
+impl_gauge_ops!(PsiGauge => KilopascalGauge, BarGauge, PsiGauge);
+
+impl_from_quantity!(PsiGauge, KilopascalGauge);
+impl_from_quantity!(PsiGauge, BarGauge);
+
+/// A thousand pascals, as a prefixed `Pascal`.
+pub type Kilopascal = Prefixed<Kilo, Pascal>;
+pub type Kilopascals = Kilopascal;
+
+/// A million pascals, as a prefixed `Pascal`.
+pub type Megapascal = Prefixed<Mega, Pascal>;
+pub type Megapascals = Megapascal;
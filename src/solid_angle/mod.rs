@@ -0,0 +1,47 @@
+//! Units of solid angle, a dimension of its own rather than a square of plane angle.
+//!
+//! ```
+//! use metric::composite::Per;
+//! use metric::solid_angle::{SquareDegree, Steradian};
+//!
+//! let beam = SquareDegree(100.0);
+//! let density: Per<Steradian> = 1.0 / Steradian::from(beam);
+//! assert!(Steradian::SPHERE > beam);
+//! println!("{:?}", density);
+//! ```
+
+use core::f64::consts::PI;
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Steradian(pub f64);
+pub type Steradians = Steradian;
+
+impl_full_unit!(Steradian);
+impl_unit_debug!(Steradian => "sr");
+impl_quantity!(Steradian => Steradian, 1.0);
+impl_composite_base!(Steradian);
+
+impl Steradian {
+    /// The solid angle of a whole sphere.
+    pub const SPHERE: Steradian = Steradian(4.0 * PI);
+}
+
+/// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct SquareDegree(pub f64);
+pub type SquareDegrees = SquareDegree;
+
+impl_full_unit!(SquareDegree);
+impl_unit_debug!(SquareDegree => "deg²");
+impl_quantity!(SquareDegree => Steradian, (PI / 180.0) * (PI / 180.0));
+impl_composite_base!(SquareDegree);
+
+impl SquareDegree {
+    /// The solid angle of a whole sphere.
+    pub const SPHERE: SquareDegree = SquareDegree(129_600.0 / PI);
+}
+
+impl_from_quantity!(SquareDegree, Steradian);
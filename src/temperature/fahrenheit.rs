@@ -1,19 +1,19 @@
 //! Defines the `Fahrenheit` temperature newtype and related trait impls
 
-use core::{self, fmt};
-
-use composite::UnitName;
-
-use temperature::Celsius;
 use temperature::Kelvin;
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fahrenheit(pub f64);
 
 impl_basic_ops!(Fahrenheit);
+impl_rem!(Fahrenheit);
 impl_unit_debug!(Fahrenheit => "°F");
+impl_quantity!(Fahrenheit => Kelvin, 5. / 9., 459.67 * 5. / 9.);
+impl_composite_base!(Fahrenheit);
 impl_partial_ord!(Fahrenheit);
+impl_math!(Fahrenheit);
+impl_approx_eq!(Fahrenheit);
 
-impl_from!(Celsius => Fahrenheit, |c| c * 9. / 5. + 32.);
-impl_from!(Kelvin  => Fahrenheit, |k| k * 9. / 5. - 459.67);
+impl_from_quantity!(Fahrenheit, Kelvin);
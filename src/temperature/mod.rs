@@ -1,4 +1,12 @@
 //! This module provides newtypes for performant and safe unit-aware calculations with temperatures.
+//!
+//! ```
+//! use metric::temperature::{Celsius, Kelvin};
+//!
+//! assert_eq!(Kelvin(300.0) % Kelvin(7.0), Kelvin(6.0));
+//! assert_eq!(&Celsius(25.0) + &Celsius(5.0), Celsius(30.0));
+//! assert_eq!(-Celsius(10.0), Celsius(-10.0));
+//! ```
 
 pub mod celsius;
 pub mod kelvin;
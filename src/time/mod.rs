@@ -1,103 +1,141 @@
-use core::{self, fmt};
-
-use composite::UnitName;
+use prefix::{Femto, Micro, Milli, Nano, Pico, Prefixed};
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Second(pub f64);
 pub type Seconds = Second;
 
 impl_full_unit!(Second);
 impl_unit_debug!(Second => "s");
+impl_quantity!(Second => Second, 1.0);
+impl_composite_base!(Second);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Minute(pub f64);
 pub type Minutes = Minute;
 
 impl_full_unit!(Minute);
 impl_unit_debug!(Minute => "min");
+impl_quantity!(Minute => Second, 60.0);
+impl_composite_base!(Minute);
 
-impl_from_cf!(Minute <===> 60.0 Second);
+impl_from_quantity!(Minute, Second);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Hour(pub f64);
 pub type Hours = Hour;
 
 impl_full_unit!(Hour);
 impl_unit_debug!(Hour => "hr");
+impl_quantity!(Hour => Second, 3600.0);
+impl_composite_base!(Hour);
 
-impl_from_cf!(Hour <===> 3600.0 Second);
-impl_from_cf!(Hour <===>   60.0 Minute);
+impl_from_quantity!(Hour, Second);
+impl_from_quantity!(Hour, Minute);
 
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Day(pub f64);
 pub type Days = Day;
 
 impl_full_unit!(Day);
 impl_unit_debug!(Day => "d");
+impl_quantity!(Day => Second, 86400.0);
+impl_composite_base!(Day);
 
-impl_from_cf!(Day <===> 86400.0 Second);
-impl_from_cf!(Day <===>  1440.0 Minute);
-impl_from_cf!(Day <===>    24.0 Hour  );
+impl_from_quantity!(Day, Second);
+impl_from_quantity!(Day, Minute);
+impl_from_quantity!(Day, Hour);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Year(pub f64);
 pub type Years = Year;
 
 impl_full_unit!(Year);
 impl_unit_debug!(Year => "yr", "{}yrs");
+impl_quantity!(Year => Second, 31556925.9);
+impl_composite_base!(Year);
 
-impl_from_cf!(Year <===> 31556925.9    Second);
-impl_from_cf!(Year <===>   525948.76   Minute);
-impl_from_cf!(Year <===>     8765.812  Hour  );
-impl_from_cf!(Year <===>      365.2421 Day   );
+impl_from_quantity!(Year, Second);
+impl_from_quantity!(Year, Minute);
+impl_from_quantity!(Year, Hour);
+impl_from_quantity!(Year, Day);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Decade(pub f64);
 pub type Decades = Decade;
 
 impl_full_unit!(Decade);
 impl_unit_debug!(Decade => " decade", "{} decades");
+impl_quantity!(Decade => Second, 315569259.0);
+impl_composite_base!(Decade);
 
-impl_from_cf!(Decade <===> 315569259.0   Second);
-impl_from_cf!(Decade <===>   5259487.6   Minute);
-impl_from_cf!(Decade <===>     87658.12  Hour  );
-impl_from_cf!(Decade <===>      3652.421 Day   );
-impl_from_cf!(Decade <===>        10.0   Year  );
+impl_from_quantity!(Decade, Second);
+impl_from_quantity!(Decade, Minute);
+impl_from_quantity!(Decade, Hour);
+impl_from_quantity!(Decade, Day);
+impl_from_quantity!(Decade, Year);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Century(pub f64);
 pub type Centurys = Century;
 
 impl_full_unit!(Century);
 impl_unit_debug!(Century => " century", "{} centuries");
+impl_quantity!(Century => Second, 3155692590.0);
+impl_composite_base!(Century);
 
-impl_from_cf!(Century <===> 3155692590.0    Second);
-impl_from_cf!(Century <===>   52594876.0    Minute);
-impl_from_cf!(Century <===>     876581.2    Hour  );
-impl_from_cf!(Century <===>      36524.21   Day   );
-impl_from_cf!(Century <===>        100.0    Year  );
-impl_from_cf!(Century <===>         10.0    Decade);
+impl_from_quantity!(Century, Second);
+impl_from_quantity!(Century, Minute);
+impl_from_quantity!(Century, Hour);
+impl_from_quantity!(Century, Day);
+impl_from_quantity!(Century, Year);
+impl_from_quantity!(Century, Decade);
 
 /// A newtype that wraps around `f64` and provides convenience functions for unit-aware and type-safe manipulation.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Millennium(pub f64);
 pub type Millennia = Millennium;
 
 impl_full_unit!(Millennium);
 impl_unit_debug!(Millennium => " millennium", "{} millennia");
-
-impl_from_cf!(Millennium <===> 31556925900.0    Second );
-impl_from_cf!(Millennium <===>   525948760.0    Minute );
-impl_from_cf!(Millennium <===>     8765812.0    Hour   );
-impl_from_cf!(Millennium <===>      365242.1    Day    );
-impl_from_cf!(Millennium <===>        1000.0    Year   );
-impl_from_cf!(Millennium <===>         100.0    Decade );
-impl_from_cf!(Millennium <===>          10.0    Century);
+impl_quantity!(Millennium => Second, 31556925900.0);
+impl_composite_base!(Millennium);
+
+impl_from_quantity!(Millennium, Second);
+impl_from_quantity!(Millennium, Minute);
+impl_from_quantity!(Millennium, Hour);
+impl_from_quantity!(Millennium, Day);
+impl_from_quantity!(Millennium, Year);
+impl_from_quantity!(Millennium, Decade);
+impl_from_quantity!(Millennium, Century);
+
+/// One thousandth of a second, as a prefixed `Second`.
+pub type Millisecond = Prefixed<Milli, Second>;
+pub type Milliseconds = Millisecond;
+/// One millionth of a second, as a prefixed `Second`.
+pub type Microsecond = Prefixed<Micro, Second>;
+pub type Microseconds = Microsecond;
+/// One billionth of a second, as a prefixed `Second`.
+pub type Nanosecond = Prefixed<Nano, Second>;
+pub type Nanoseconds = Nanosecond;
+/// 10<sup>-12</sup> of a second, as a prefixed `Second`.
+pub type Picosecond = Prefixed<Pico, Second>;
+pub type Picoseconds = Picosecond;
+/// 10<sup>-15</sup> of a second, as a prefixed `Second`.
+pub type Femtosecond = Prefixed<Femto, Second>;
+pub type Femtoseconds = Femtosecond;
@@ -0,0 +1,90 @@
+//! Provides `Total`, a wrapper that gives any unit a total order so it can be used with `Ord`, `Eq` and `Hash` based
+//! collections.
+//!
+//! Composites compare with `PartialEq` and `PartialOrd` like any other unit, converting the right hand side first.
+//!
+//! ```
+//! use std::collections::{BTreeMap, HashSet};
+//!
+//! use metric::composite::{Div, Mul};
+//! use metric::energy::Joule;
+//! use metric::force::Newton;
+//! use metric::length::metric::{Kilometer, Meter};
+//! use metric::time::{Hour, Second};
+//! use metric::total::Total;
+//!
+//! assert_eq!(Kilometer(36.0) / Hour(1.0), Meter(10.0) / Second(1.0));
+//! assert!(Kilometer(36.0) / Hour(1.0) < Meter(11.0) / Second(1.0));
+//! assert_eq!(Mul::<Newton, Meter>::from_value(5.0), Joule(5.0));
+//!
+//! let mut speeds: Vec<Div<Meter, Second>> = vec![Meter(3.0) / Second(1.0), Meter(-1.0) / Second(1.0)];
+//! speeds.sort_by(|a, b| a.partial_cmp(b).unwrap());
+//! assert_eq!(speeds[0], Meter(-1.0) / Second(1.0));
+//!
+//! let mut stops = BTreeMap::new();
+//! stops.insert(Total(Kilometer(5.0)), "depot");
+//! stops.insert(Total(Kilometer(1.5)), "school");
+//! stops.insert(Total(Kilometer(5.0)), "warehouse");
+//! assert_eq!(stops.values().copied().collect::<Vec<_>>(), ["school", "warehouse"]);
+//!
+//! assert!(Total(Meter(-0.0)) < Total(Meter(0.0)));
+//! assert_eq!(Total(Meter(core::f64::NAN)), Total(Meter(core::f64::NAN)));
+//! assert!(Total(Meter(core::f64::INFINITY)) < Total(Meter(core::f64::NAN)));
+//!
+//! let seen: HashSet<_> = speeds.iter().chain(&speeds).map(|&speed| Total(speed)).collect();
+//! assert_eq!(seen.len(), 2);
+//! ```
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use composite::Unit;
+
+/// Wraps a unit so that it is compared with `f64::total_cmp` and hashed by its bit pattern.
+///
+/// This makes quantities usable as `BTreeMap` or `HashMap` keys. Values are ordered in their own unit,
+/// with `-0.0` sorting before `0.0` and NaNs at either end, as described by `f64::total_cmp`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total<T>(pub T);
+
+impl<T> Ord for Total<T>
+    where T: Unit
+{
+    fn cmp(&self, other: &Total<T>) -> Ordering {
+        self.0.inner().total_cmp(&other.0.inner())
+    }
+}
+
+impl<T> PartialOrd for Total<T>
+    where T: Unit
+{
+    fn partial_cmp(&self, other: &Total<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Total<T>
+    where T: Unit
+{
+    fn eq(&self, other: &Total<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Total<T> where T: Unit {}
+
+impl<T> Hash for Total<T>
+    where T: Unit
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.inner().to_bits().hash(state);
+    }
+}
+
+impl<T> From<T> for Total<T>
+    where T: Unit
+{
+    fn from(t: T) -> Self {
+        Total(t)
+    }
+}